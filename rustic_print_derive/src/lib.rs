@@ -0,0 +1,196 @@
+//! Derive macros for [Rustic Print](https://crates.io/crates/rustic_print).
+//!
+//! Enable the `derive` feature of `rustic_print` rather than depending on this crate directly.
+//! See `rustic_print::table::Tabled` for the supported attributes.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, ExprPath, Fields, LitInt, LitStr, Result};
+
+/// Derives `rustic_print::table::Tabled` for a struct with named fields.
+#[proc_macro_derive(Tabled, attributes(tabled))]
+pub fn derive_tabled(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_tabled(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// A field that is listed as a column, with the options from its `#[tabled(...)]` attributes.
+struct Column {
+    ident: syn::Ident,
+    header: String,
+    alignment: Option<TokenStream2>,
+    format: Option<ExprPath>,
+    order: Option<LitInt>,
+}
+
+/// Generates the `Tabled` implementation for the given struct.
+fn expand_tabled(input: &DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "Tabled can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Tabled can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut columns = Vec::new();
+    for field in fields {
+        if let Some(column) = parse_column(field)? {
+            columns.push(column);
+        }
+    }
+    let columns = order_columns(columns)?;
+
+    let headers = columns.iter().map(|column| &column.header);
+    let options = columns.iter().map(|column| match &column.alignment {
+        Some(alignment) => quote! {
+            ::rustic_print::table::ColumnOptions {
+                alignment: ::core::option::Option::Some(#alignment),
+                ..::core::default::Default::default()
+            }
+        },
+        None => quote! { ::core::default::Default::default() },
+    });
+    let cells = columns.iter().map(|column| {
+        let ident = &column.ident;
+        match &column.format {
+            Some(format) => quote! { ::std::string::ToString::to_string(&(#format)(&self.#ident)) },
+            None => quote! { ::std::string::ToString::to_string(&self.#ident) },
+        }
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rustic_print::table::Tabled for #name #ty_generics #where_clause {
+            fn headers() -> ::std::vec::Vec<::std::string::String> {
+                ::std::vec![#(::std::string::String::from(#headers)),*]
+            }
+
+            fn column_options() -> ::std::vec::Vec<::rustic_print::table::ColumnOptions> {
+                ::std::vec![#(#options),*]
+            }
+
+            fn row(&self) -> ::std::vec::Vec<::std::string::String> {
+                ::std::vec![#(#cells),*]
+            }
+        }
+    })
+}
+
+/// Puts the columns in display order.
+///
+/// Columns with an explicit `order` take that position, and the rest fill the remaining
+/// positions in declaration order.
+///
+/// # Errors
+///
+/// Returns an error if two columns ask for the same position, or a position is past the last
+/// column.
+fn order_columns(columns: Vec<Column>) -> Result<Vec<Column>> {
+    let mut slots: Vec<Option<Column>> = (0..columns.len()).map(|_| None).collect();
+    let mut unordered = Vec::new();
+
+    for column in columns {
+        let Some(order) = &column.order else {
+            unordered.push(column);
+            continue;
+        };
+        let position: usize = order.base10_parse()?;
+        match slots.get(position) {
+            None => {
+                return Err(Error::new_spanned(
+                    order,
+                    format!(
+                        "order must be less than the number of columns, {}",
+                        slots.len()
+                    ),
+                ))
+            }
+            Some(Some(_)) => {
+                return Err(Error::new_spanned(
+                    order,
+                    format!("another column already has order {}", position),
+                ))
+            }
+            Some(None) => {}
+        }
+        slots[position] = Some(column);
+    }
+
+    let mut unordered = unordered.into_iter();
+    Ok(slots
+        .into_iter()
+        .map(|slot| {
+            slot.or_else(|| unordered.next())
+                .expect("every free position has an unordered column")
+        })
+        .collect())
+}
+
+/// Reads the `#[tabled(...)]` attributes of a field.
+///
+/// Returns `None` for fields marked `skip`.
+fn parse_column(field: &syn::Field) -> Result<Option<Column>> {
+    let ident = field.ident.clone().expect("named fields have identifiers");
+    let mut column = Column {
+        header: ident.to_string(),
+        ident,
+        alignment: None,
+        format: None,
+        order: None,
+    };
+    let mut skip = false;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("tabled"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                column.header = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("skip") {
+                skip = true;
+            } else if meta.path.is_ident("align") {
+                let align = meta.value()?.parse::<LitStr>()?;
+                column.alignment = Some(match align.value().as_str() {
+                    "left" => quote! { ::rustic_print::table::Alignment::Left },
+                    "right" => quote! { ::rustic_print::table::Alignment::Right },
+                    "center" => quote! { ::rustic_print::table::Alignment::Center },
+                    "auto" => quote! { ::rustic_print::table::Alignment::Auto },
+                    _ => {
+                        return Err(Error::new_spanned(
+                            align,
+                            "expected \"left\", \"right\", \"center\" or \"auto\"",
+                        ))
+                    }
+                });
+            } else if meta.path.is_ident("format") {
+                column.format = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else if meta.path.is_ident("order") {
+                let order = meta.value()?.parse::<LitInt>()?;
+                order.base10_parse::<usize>()?;
+                column.order = Some(order);
+            } else {
+                return Err(meta.error("unknown tabled attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(if skip { None } else { Some(column) })
+}
@@ -0,0 +1,9 @@
+use rustic_print::table::Tabled;
+
+#[derive(Tabled)]
+struct Server {
+    #[tabled(align = "middle")]
+    name: String,
+}
+
+fn main() {}
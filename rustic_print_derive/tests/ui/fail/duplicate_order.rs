@@ -0,0 +1,11 @@
+use rustic_print::table::Tabled;
+
+#[derive(Tabled)]
+struct Server {
+    #[tabled(order = 0)]
+    name: String,
+    #[tabled(order = 0)]
+    region: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rustic_print::table::Tabled;
+
+#[derive(Tabled)]
+struct Server {
+    #[tabled(hidden)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use rustic_print::table::{Table, Tabled};
+
+fn megabytes(bytes: &u64) -> String {
+    format!("{} MB", bytes / 1_000_000)
+}
+
+#[derive(Tabled)]
+struct Server {
+    name: String,
+    #[tabled(skip)]
+    token: String,
+    #[tabled(rename = "Memory", format = "megabytes", order = 0)]
+    memory: u64,
+    region: String,
+}
+
+fn main() {
+    let servers = [Server {
+        name: "web-1".to_string(),
+        token: "secret".to_string(),
+        memory: 512_000_000,
+        region: "eu".to_string(),
+    }];
+
+    assert_eq!(Server::headers(), ["Memory", "name", "region"]);
+    assert_eq!(servers[0].row(), ["512 MB", "web-1", "eu"]);
+    assert!(!Table::from_tabled(&servers).to_csv().contains(&servers[0].token));
+}
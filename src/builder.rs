@@ -0,0 +1,220 @@
+use crate::color::{ColorChoice, ColorDepth};
+use crate::streams::StreamPolicy;
+use crate::theme::Theme;
+use crate::RusticPrint;
+use std::io::{stderr, stdout, IsTerminal, Stderr, Stdout, Write};
+use std::sync::Mutex;
+
+/// Configures and creates a [`RusticPrint`] instance.
+///
+/// By default, content is written to stdout and diagnostics to stderr, using the theme from the
+/// environment (see [`Theme::from_env`]) and styling output only when it goes to a terminal.
+///
+/// # Example
+///
+/// ```rust
+/// use rustic_print::builder::RusticPrintBuilder;
+/// use rustic_print::streams::StreamPolicy;
+///
+/// let printer = RusticPrintBuilder::new()
+///     .writer(Vec::new())
+///     .diagnostic_writer(Vec::new())
+///     .streams(StreamPolicy::Split)
+///     .build();
+///
+/// printer.listing(vec!["report.csv"]);
+/// printer.warning("Disk space is low.");
+///
+/// let (content, diagnostics) = printer.into_writers();
+/// assert_eq!(String::from_utf8(content).unwrap(), "* report.csv\n");
+/// assert!(String::from_utf8(diagnostics).unwrap().contains("Disk space is low."));
+/// ```
+pub struct RusticPrintBuilder<W: Write = Stdout, E: Write = Stderr> {
+    writer: W,
+    diagnostic_writer: E,
+    streams: StreamPolicy,
+    theme: Theme,
+    color_choice: ColorChoice,
+    color_depth: Option<ColorDepth>,
+    writer_is_terminal: bool,
+    diagnostic_writer_is_terminal: bool,
+}
+
+impl RusticPrintBuilder {
+    /// Creates a new builder that writes content to stdout and diagnostics to stderr.
+    ///
+    /// The theme is read from the environment as with [`Theme::from_env`], so
+    /// `RUSTIC_PRINT_THEME` and overrides such as `RUSTIC_PRINT_ERROR_BG=red` apply without any
+    /// setup. The environment is read once per process. A theme file that can't be loaded is
+    /// replaced by the default theme, and an invalid override is skipped while the others still
+    /// apply; build with `.theme(Theme::from_env()?)` to report these problems instead.
+    ///
+    /// # Returns
+    ///
+    /// A new `RusticPrintBuilder` instance.
+    pub fn new() -> RusticPrintBuilder {
+        RusticPrintBuilder {
+            writer_is_terminal: stdout().is_terminal(),
+            diagnostic_writer_is_terminal: stderr().is_terminal(),
+            writer: stdout(),
+            diagnostic_writer: stderr(),
+            streams: StreamPolicy::Split,
+            theme: Theme::from_env_cached(),
+            color_choice: ColorChoice::Auto,
+            color_depth: None,
+        }
+    }
+}
+
+/// Returns a builder that writes content to stdout and diagnostics to stderr.
+impl Default for RusticPrintBuilder {
+    fn default() -> Self {
+        RusticPrintBuilder::new()
+    }
+}
+
+impl<W: Write, E: Write> RusticPrintBuilder<W, E> {
+    /// Sets the main writer, which receives all regular content.
+    ///
+    /// The writer is treated as not being a terminal, so with `ColorChoice::Auto` its output is
+    /// not styled. Use [`RusticPrintBuilder::terminal_writer`] for writers that may be terminals.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination for content output.
+    pub fn writer<W2: Write>(self, writer: W2) -> RusticPrintBuilder<W2, E> {
+        self.replace_writer(writer, false)
+    }
+
+    /// Sets the main writer, detecting whether it is connected to a terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination for content output, such as stdout, stderr or a file.
+    pub fn terminal_writer<W2: Write + IsTerminal>(self, writer: W2) -> RusticPrintBuilder<W2, E> {
+        let is_terminal = writer.is_terminal();
+        self.replace_writer(writer, is_terminal)
+    }
+
+    /// Sets the diagnostic writer, which receives errors, warnings, cautions and prompts when
+    /// the stream policy is [`StreamPolicy::Split`].
+    ///
+    /// The writer is treated as not being a terminal, so with `ColorChoice::Auto` its output is
+    /// not styled. Use [`RusticPrintBuilder::terminal_diagnostic_writer`] for writers that may
+    /// be terminals.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagnostic_writer` - The destination for diagnostic output.
+    pub fn diagnostic_writer<E2: Write>(self, diagnostic_writer: E2) -> RusticPrintBuilder<W, E2> {
+        self.replace_diagnostic_writer(diagnostic_writer, false)
+    }
+
+    /// Sets the diagnostic writer, detecting whether it is connected to a terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagnostic_writer` - The destination for diagnostic output, such as stderr or a file.
+    pub fn terminal_diagnostic_writer<E2: Write + IsTerminal>(
+        self,
+        diagnostic_writer: E2,
+    ) -> RusticPrintBuilder<W, E2> {
+        let is_terminal = diagnostic_writer.is_terminal();
+        self.replace_diagnostic_writer(diagnostic_writer, is_terminal)
+    }
+
+    /// Sets the policy deciding which writer each kind of output is sent to.
+    ///
+    /// # Arguments
+    ///
+    /// * `streams` - The stream policy to use.
+    pub fn streams(mut self, streams: StreamPolicy) -> Self {
+        self.streams = streams;
+        self
+    }
+
+    /// Sets the theme used to style every kind of output.
+    ///
+    /// This replaces the theme read from the environment. Call [`Theme::apply_env_overrides`] on
+    /// the theme first to let users still override individual colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The theme to use.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets whether output is styled with colors and text attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `color_choice` - The color choice to use; `ColorChoice::Auto` by default.
+    pub fn color_choice(mut self, color_choice: ColorChoice) -> Self {
+        self.color_choice = color_choice;
+        self
+    }
+
+    /// Sets the color depth that theme colors are converted to before they are printed.
+    ///
+    /// By default, the depth is detected from the `COLORTERM` and `TERM` environment variables.
+    ///
+    /// # Arguments
+    ///
+    /// * `color_depth` - The color depth of the output.
+    pub fn color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = Some(color_depth);
+        self
+    }
+
+    /// Creates the configured `RusticPrint` instance.
+    ///
+    /// # Returns
+    ///
+    /// A new `RusticPrint` instance.
+    pub fn build(self) -> RusticPrint<W, E> {
+        RusticPrint {
+            writer: Mutex::new(self.writer),
+            diagnostic_writer: Mutex::new(self.diagnostic_writer),
+            streams: self.streams,
+            theme: self.theme,
+            color_choice: self.color_choice,
+            color_depth: self.color_depth,
+            writer_is_terminal: self.writer_is_terminal,
+            diagnostic_writer_is_terminal: self.diagnostic_writer_is_terminal,
+        }
+    }
+
+    /// Swaps the main writer, keeping every other setting.
+    fn replace_writer<W2: Write>(self, writer: W2, is_terminal: bool) -> RusticPrintBuilder<W2, E> {
+        RusticPrintBuilder {
+            writer,
+            diagnostic_writer: self.diagnostic_writer,
+            streams: self.streams,
+            theme: self.theme,
+            color_choice: self.color_choice,
+            color_depth: self.color_depth,
+            writer_is_terminal: is_terminal,
+            diagnostic_writer_is_terminal: self.diagnostic_writer_is_terminal,
+        }
+    }
+
+    /// Swaps the diagnostic writer, keeping every other setting.
+    fn replace_diagnostic_writer<E2: Write>(
+        self,
+        diagnostic_writer: E2,
+        is_terminal: bool,
+    ) -> RusticPrintBuilder<W, E2> {
+        RusticPrintBuilder {
+            writer: self.writer,
+            diagnostic_writer,
+            streams: self.streams,
+            theme: self.theme,
+            color_choice: self.color_choice,
+            color_depth: self.color_depth,
+            writer_is_terminal: self.writer_is_terminal,
+            diagnostic_writer_is_terminal: is_terminal,
+        }
+    }
+}
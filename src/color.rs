@@ -0,0 +1,386 @@
+use crate::style_options::StyleOptions;
+use crossterm::style::{Attribute, Color};
+use std::env;
+use std::fmt::{self, Display, Formatter};
+
+/// Controls whether output is styled with colors and text attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Style output only when writing to a terminal, honoring the `NO_COLOR`, `CLICOLOR` and
+    /// `CLICOLOR_FORCE` environment variables.
+    #[default]
+    Auto,
+    /// Always style output, even when it is redirected.
+    Always,
+    /// Never style output.
+    Never,
+}
+
+impl ColorChoice {
+    /// Decides whether output sent to a writer should be styled.
+    ///
+    /// With `Auto`, the environment is checked in this order:
+    /// - `NO_COLOR` set to any non-empty value disables styling.
+    /// - `CLICOLOR_FORCE` set to anything other than `0` enables styling.
+    /// - `CLICOLOR` set to `0` disables styling.
+    /// - Otherwise, output is styled only if the writer is a terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_terminal` - Whether the writer is connected to a terminal.
+    ///
+    /// # Returns
+    ///
+    /// `true` if colors and text attributes should be emitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::color::ColorChoice;
+    ///
+    /// assert!(ColorChoice::Always.should_colorize(false));
+    /// assert!(!ColorChoice::Never.should_colorize(true));
+    /// ```
+    pub fn should_colorize(self, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                let var = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+
+                if var("NO_COLOR").is_some() {
+                    false
+                } else if var("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+                    true
+                } else if var("CLICOLOR").is_some_and(|value| value == "0") {
+                    false
+                } else {
+                    is_terminal
+                }
+            }
+        }
+    }
+
+    /// Creates a painter for a writer according to this choice.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_terminal` - Whether the writer is connected to a terminal.
+    ///
+    /// # Returns
+    ///
+    /// A `Painter` that styles content only if styling is enabled.
+    pub(crate) fn painter(self, is_terminal: bool) -> Painter {
+        Painter::new(self.should_colorize(is_terminal), ColorDepth::detect())
+    }
+}
+
+/// The range of colors a terminal can display.
+///
+/// Depths are ordered, so `ColorDepth::Ansi16 < ColorDepth::TrueColor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// No colors at all; text attributes may still be used.
+    None,
+    /// The 16 basic ANSI colors.
+    Ansi16,
+    /// The 256-color ANSI palette.
+    Ansi256,
+    /// 24-bit RGB colors.
+    TrueColor,
+}
+
+/// The RGB values of the 16 basic colors, in ANSI index order.
+const ANSI16_PALETTE: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (128, 0, 0)),
+    (Color::DarkGreen, (0, 128, 0)),
+    (Color::DarkYellow, (128, 128, 0)),
+    (Color::DarkBlue, (0, 0, 128)),
+    (Color::DarkMagenta, (128, 0, 128)),
+    (Color::DarkCyan, (0, 128, 128)),
+    (Color::Grey, (192, 192, 192)),
+    (Color::DarkGrey, (128, 128, 128)),
+    (Color::Red, (255, 0, 0)),
+    (Color::Green, (0, 255, 0)),
+    (Color::Yellow, (255, 255, 0)),
+    (Color::Blue, (0, 0, 255)),
+    (Color::Magenta, (255, 0, 255)),
+    (Color::Cyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// The channel levels of the 6x6x6 color cube in the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl ColorDepth {
+    /// Detects the color depth of the terminal from the environment.
+    ///
+    /// `COLORTERM=truecolor` (or `24bit`) means true color. Otherwise `TERM` is used: `dumb`
+    /// means no colors, a `-direct` suffix means true color, and a `256color` suffix means the
+    /// 256-color palette. Anything else falls back to the 16 basic colors.
+    ///
+    /// # Returns
+    ///
+    /// The detected `ColorDepth`.
+    pub fn detect() -> ColorDepth {
+        let colorterm = env::var("COLORTERM")
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorDepth::TrueColor;
+        }
+
+        let term = env::var("TERM").unwrap_or_default().to_ascii_lowercase();
+        if term == "dumb" {
+            ColorDepth::None
+        } else if term.ends_with("-direct") {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    /// Converts a color to the nearest color this depth can display.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to convert.
+    ///
+    /// # Returns
+    ///
+    /// The converted color, or `None` if this depth cannot display colors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crossterm::style::Color;
+    /// use rustic_print::color::ColorDepth;
+    ///
+    /// let orange = Color::Rgb { r: 255, g: 135, b: 0 };
+    /// assert_eq!(ColorDepth::TrueColor.convert(orange), Some(orange));
+    /// assert_eq!(ColorDepth::Ansi256.convert(orange), Some(Color::AnsiValue(208)));
+    /// assert_eq!(ColorDepth::Ansi16.convert(orange), Some(Color::Yellow));
+    /// assert_eq!(ColorDepth::None.convert(orange), None);
+    /// ```
+    pub fn convert(self, color: Color) -> Option<Color> {
+        match (self, color) {
+            (ColorDepth::None, _) => None,
+            (ColorDepth::TrueColor, _) => Some(color),
+            (ColorDepth::Ansi256, Color::Rgb { r, g, b }) => {
+                Some(Color::AnsiValue(nearest_ansi256((r, g, b))))
+            }
+            (ColorDepth::Ansi16, Color::Rgb { r, g, b }) => Some(nearest_ansi16((r, g, b))),
+            (ColorDepth::Ansi16, Color::AnsiValue(index)) => {
+                Some(nearest_ansi16(ansi256_to_rgb(index)))
+            }
+            _ => Some(color),
+        }
+    }
+}
+
+/// Returns the RGB value of a color in the 256-color palette.
+fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16_PALETTE[index as usize].1,
+        16..=231 => {
+            let cube = index - 16;
+            (
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// Returns the squared distance between two RGB colors.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+/// Returns the closest of the 16 basic colors to an RGB color.
+fn nearest_ansi16(rgb: (u8, u8, u8)) -> Color {
+    ANSI16_PALETTE
+        .iter()
+        .min_by_key(|(_, candidate)| distance(rgb, *candidate))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// Returns the index of the closest color in the 256-color cube or grayscale ramp.
+fn nearest_ansi256(rgb: (u8, u8, u8)) -> u8 {
+    let nearest_level = |value: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - value as i32).abs())
+            .unwrap_or(0) as u8
+    };
+    let cube = 16 + 36 * nearest_level(rgb.0) + 6 * nearest_level(rgb.1) + nearest_level(rgb.2);
+
+    let average = (rgb.0 as u32 + rgb.1 as u32 + rgb.2 as u32) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    if distance(rgb, ansi256_to_rgb(gray)) < distance(rgb, ansi256_to_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// Applies styles to content, or leaves it plain when styling is disabled for the output.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Painter {
+    enabled: bool,
+    depth: ColorDepth,
+}
+
+impl Painter {
+    /// Creates a new painter.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether styles should be applied.
+    /// * `depth` - The color depth that colors are converted to.
+    pub(crate) fn new(enabled: bool, depth: ColorDepth) -> Painter {
+        Painter { enabled, depth }
+    }
+
+    /// Adapts a style to what the output supports.
+    ///
+    /// # Arguments
+    ///
+    /// * `style_options` - The style to adapt, if any.
+    ///
+    /// # Returns
+    ///
+    /// The style to use, with colors converted to the nearest ones the output can display, or
+    /// `None` if the output should not be styled.
+    pub(crate) fn adapt(&self, style_options: Option<&StyleOptions>) -> Option<StyleOptions> {
+        if !self.enabled {
+            return None;
+        }
+        style_options.map(|style_options| StyleOptions {
+            foreground: style_options
+                .foreground
+                .and_then(|color| self.depth.convert(color)),
+            background: style_options
+                .background
+                .and_then(|color| self.depth.convert(color)),
+            attributes: style_options.attributes,
+        })
+    }
+
+    /// Styles content with the given style, if styling is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `style_options` - The style to apply.
+    /// * `content` - The content to style.
+    ///
+    /// # Returns
+    ///
+    /// The styled content, which prints without escape codes when styling is disabled.
+    pub(crate) fn paint<D: Display>(&self, style_options: &StyleOptions, content: D) -> Painted<D> {
+        Painted {
+            style: self.adapt(Some(style_options)),
+            content,
+        }
+    }
+}
+
+/// Content that prints with the escape codes of its style.
+///
+/// crossterm's `StyledContent` drops colors whenever `NO_COLOR` is set, which would defeat an
+/// explicit `ColorChoice::Always`. The [`Painter`] has already decided whether to style, so the
+/// escape codes are written here instead.
+#[derive(Debug, Clone)]
+pub(crate) struct Painted<D> {
+    style: Option<StyleOptions>,
+    content: D,
+}
+
+impl<D> Painted<D> {
+    /// Pairs content with the style to print it in.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The style to apply, or `None` to print the content as-is.
+    /// * `content` - The content to style.
+    pub(crate) fn new(style: Option<StyleOptions>, content: D) -> Painted<D> {
+        Painted { style, content }
+    }
+}
+
+/// Writes the content between the escape codes that set and reset its style, in the same form
+/// as crossterm's `PrintStyledContent`.
+impl<D: Display> Display for Painted<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some(style) = &self.style else {
+            return write!(f, "{}", self.content);
+        };
+
+        if let Some(background) = style.background {
+            write!(f, "\x1b[{}m", sgr_color(background, "48", "49"))?;
+        }
+        if let Some(foreground) = style.foreground {
+            write!(f, "\x1b[{}m", sgr_color(foreground, "38", "39"))?;
+        }
+        for attribute in Attribute::iterator().filter(|&a| style.attributes.has(a)) {
+            write!(f, "\x1b[{}m", attribute.sgr())?;
+        }
+
+        write!(f, "{}", self.content)?;
+
+        // Resetting attributes resets the colors too.
+        if !style.attributes.is_empty() {
+            f.write_str("\x1b[0m")
+        } else {
+            if style.background.is_some() {
+                f.write_str("\x1b[49m")?;
+            }
+            if style.foreground.is_some() {
+                f.write_str("\x1b[39m")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Returns the SGR parameters that set a foreground or background color.
+///
+/// # Arguments
+///
+/// * `color` - The color to set.
+/// * `set` - The parameter introducing a color, `38` for the foreground or `48` for the background.
+/// * `reset` - The parameter restoring the default color, `39` or `49`.
+fn sgr_color(color: Color, set: &str, reset: &str) -> String {
+    let index = match color {
+        Color::Reset => return reset.to_string(),
+        Color::Rgb { r, g, b } => return format!("{};2;{};{};{}", set, r, g, b),
+        Color::AnsiValue(index) => index,
+        Color::Black => 0,
+        Color::DarkRed => 1,
+        Color::DarkGreen => 2,
+        Color::DarkYellow => 3,
+        Color::DarkBlue => 4,
+        Color::DarkMagenta => 5,
+        Color::DarkCyan => 6,
+        Color::Grey => 7,
+        Color::DarkGrey => 8,
+        Color::Red => 9,
+        Color::Green => 10,
+        Color::Yellow => 11,
+        Color::Blue => 12,
+        Color::Magenta => 13,
+        Color::Cyan => 14,
+        Color::White => 15,
+    };
+    format!("{};5;{}", set, index)
+}
@@ -0,0 +1,48 @@
+use std::fmt;
+use std::io;
+
+/// Errors that can occur while rendering output or prompting the user.
+#[derive(Debug)]
+pub enum Error {
+    /// Writing output or reading input failed, e.g. because of a closed pipe.
+    Io(io::Error),
+    /// The terminal could not be used, e.g. because raw mode is unavailable without a TTY.
+    TerminalUnavailable(io::Error),
+    /// The user cancelled the prompt, e.g. by pressing Ctrl+C.
+    Cancelled,
+    /// The input or arguments were not valid for the requested operation.
+    InvalidInput(String),
+}
+
+/// Formats the error as a human-readable message.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::TerminalUnavailable(err) => write!(f, "terminal unavailable: {}", err),
+            Error::Cancelled => write!(f, "cancelled by user"),
+            Error::InvalidInput(message) => write!(f, "invalid input: {}", message),
+        }
+    }
+}
+
+/// Exposes the underlying I/O error, if any, as the source of this error.
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) | Error::TerminalUnavailable(err) => Some(err),
+            Error::Cancelled | Error::InvalidInput(_) => None,
+        }
+    }
+}
+
+/// Converts an `io::Error` into an `Error::Io` variant.
+///
+/// # Arguments
+///
+/// * `err` - The I/O error to wrap.
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
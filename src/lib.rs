@@ -26,6 +26,144 @@
 //! ]);
 //! ```
 //!
+//! ## Custom Output
+//!
+//! By default output is printed to the terminal. Use `with_writer` to render all of it into any `Write`
+//! implementation instead, such as a log file, a locked stderr, or a buffer in tests.
+//!
+//! **Example:**
+//! ```rust
+//! use rustic_print::RusticPrint;
+//!
+//! let printer = RusticPrint::with_writer(Vec::new());
+//! printer.text("Written to a buffer.");
+//!
+//! let output = String::from_utf8(printer.into_inner()).unwrap();
+//! assert!(output.contains("Written to a buffer."));
+//! ```
+//!
+//! ## Themes
+//!
+//! Every message helper, heading, prompt and table header is styled by a `Theme`. Pick one of the built-in themes (`Theme::default()`, `Theme::monochrome()`, `Theme::high_contrast()` or `Theme::solarized()`), tweak any entry, and build a `RusticPrint` with it so all of your tools share one look. Styles combine colors with text attributes such as bold, italic, underline, dim, blink, reverse, strikethrough and hidden.
+//!
+//! **Example:**
+//! ```rust
+//! use crossterm::style::Attribute;
+//! use rustic_print::style_options::StyleOptions;
+//! use rustic_print::theme::Theme;
+//! use rustic_print::RusticPrint;
+//!
+//! let mut theme = Theme::solarized();
+//! theme.error.block_type = Some("FAILED".to_string());
+//! theme.comment.style = Some(StyleOptions::default().with_attribute(Attribute::Dim));
+//!
+//! let printer = RusticPrint::builder().theme(theme).build();
+//! printer.error("Could not reach the database.");
+//! ```
+//!
+//! Themes can also be loaded from a TOML or JSON file with `Theme::from_file` (behind the `toml` and
+//! `json` cargo features), or from the environment with `Theme::from_env`. `RUSTIC_PRINT_THEME` names a
+//! built-in theme or a theme file, and variables such as `RUSTIC_PRINT_ERROR_BG=red` or
+//! `RUSTIC_PRINT_TITLE_FG=#2aa198` override individual colors, while `RUSTIC_PRINT_ERROR_ATTRS=bold`
+//! sets text attributes. Colors can be given as names, `#rrggbb` hex codes, or ANSI 256-color
+//! indices.
+//!
+//! `RusticPrint::new()` and `RusticPrint::builder()` start from `Theme::from_env()`, so users can
+//! re-skin any tool built on rustic_print without the author doing anything. The environment is
+//! read once per process; a theme file that can't be loaded falls back to the default theme, and
+//! an invalid override is skipped while the rest still apply. Use `.theme(Theme::from_env()?)` to
+//! report those problems instead. Passing a theme to `.theme(...)` replaces it, so call
+//! `theme.apply_env_overrides()` first to keep honoring the color overrides.
+//!
+//! ## Colors
+//!
+//! Styling is only emitted when it is wanted. With the default `ColorChoice::Auto`, output is styled
+//! only when it goes to a terminal, `NO_COLOR` turns styling off, `CLICOLOR=0` turns it off, and
+//! `CLICOLOR_FORCE=1` turns it on even when output is redirected. Pass `ColorChoice::Always` or
+//! `ColorChoice::Never` to the builder to override the detection.
+//!
+//! Colors are also converted to the nearest ones the terminal can display. The color depth is
+//! detected from `COLORTERM` and `TERM`, so a true-color theme still looks right on a 256-color or
+//! 16-color terminal, and `TERM=dumb` drops colors entirely. Use `.color_depth(ColorDepth::Ansi16)`
+//! on the builder to pick the depth yourself.
+//!
+//! **Example:**
+//! ```rust
+//! use rustic_print::color::ColorChoice;
+//! use rustic_print::RusticPrint;
+//!
+//! let printer = RusticPrint::builder()
+//!     .color_choice(ColorChoice::Never)
+//!     .build();
+//! printer.success("No escape codes here, even in a terminal.");
+//! ```
+//!
+//! ## Output Streams
+//!
+//! By default, diagnostics (`error`, `warning` and `caution` blocks, plus the interactive prompts) are
+//! written to stderr, and everything else to stdout, so piping a tool's output never captures its
+//! diagnostics. Use the builder to pick the writers, or `StreamPolicy::Single` to send everything to
+//! one writer.
+//!
+//! **Example:**
+//! ```rust
+//! use rustic_print::streams::StreamPolicy;
+//! use rustic_print::RusticPrint;
+//!
+//! let printer = RusticPrint::builder()
+//!     .writer(std::io::stderr())
+//!     .streams(StreamPolicy::Single)
+//!     .build();
+//! printer.info("Everything goes to stderr.");
+//! ```
+//!
+//! ## Rendering to a String
+//!
+//! Use `render` to capture any output as a `String`, for example to embed it in an email or a
+//! panic message.
+//!
+//! **Example:**
+//! ```rust
+//! use rustic_print::RusticPrint;
+//!
+//! let printer = RusticPrint::new();
+//! let message = printer.render(|p| {
+//!     p.title("Deployment");
+//!     p.success("All services are healthy.");
+//! });
+//!
+//! assert!(message.contains("All services are healthy."));
+//! ```
+//!
+//! ## Error Handling
+//!
+//! Every output and prompt function has a `try_` variant (e.g. `try_success`, `try_table`, `try_confirm`) that returns a `Result` with an [`Error`] instead of panicking. Use these when output may be piped into a program that exits early, or when there may be no terminal to prompt on.
+//!
+//! **Example:**
+//! ```rust
+//! use rustic_print::{Error, RusticPrint};
+//! use std::io::{self, Write};
+//!
+//! // A writer that behaves like a pipe whose reader has gone away.
+//! struct ClosedPipe;
+//!
+//! impl Write for ClosedPipe {
+//!     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+//!         Err(io::ErrorKind::BrokenPipe.into())
+//!     }
+//!
+//!     fn flush(&mut self) -> io::Result<()> {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let printer = RusticPrint::with_writer(ClosedPipe);
+//! match printer.try_success("Operation completed successfully!") {
+//!     Err(Error::Io(err)) => assert_eq!(err.kind(), io::ErrorKind::BrokenPipe),
+//!     other => panic!("unexpected result: {:?}", other),
+//! }
+//! ```
+//!
 //! ## Tables
 //!
 //! Easily render tables by providing a vector of header strings and a vector of rows (each row is a vector of string slices).
@@ -44,12 +182,76 @@
 //! printer.table(headers, rows);
 //! ```
 //!
+//! ### Building Tables
+//!
+//! To build a table from owned values, add rows one at a time, or configure individual columns, use `Table::builder` and print it with `print_table`. Cells accept anything that implements `Display`, and each column can set its alignment, minimum and maximum width, header style and cell style.
+//!
+//! **Example:**
+//! ```rust
+//! use rustic_print::table::{Alignment, ColumnOptions, Table};
+//! use rustic_print::RusticPrint;
+//!
+//! let mut builder = Table::builder().header(["File", "Size (KB)"]).column(
+//!     1,
+//!     ColumnOptions {
+//!         alignment: Some(Alignment::Right),
+//!         ..Default::default()
+//!     },
+//! );
+//! for (file, size) in [("main.rs", 12), ("lib.rs", 148)] {
+//!     builder = builder.row([file.to_string(), size.to_string()]);
+//! }
+//!
+//! let printer = RusticPrint::new();
+//! printer.print_table(&builder.build());
+//! ```
+//!
+//! ### Alignment
+//!
+//! Columns are left-aligned unless told otherwise, and can be right-aligned or centered one at a time through `ColumnOptions`. `Alignment::Auto` right-aligns columns whose cells are all numbers; set it per column or for the whole table with `.alignment(Alignment::Auto)`. Cells may contain `\n` to span several lines; each row is as tall as its tallest cell, and `.vertical_alignment(VerticalAlignment::Middle)` (or `Bottom`, per table or per column) places the shorter cells.
+//!
+//! ### Fitting the Terminal
+//!
+//! Tables printed to a terminal fit its width (or `.max_width(..)`) by shrinking their widest columns first. Tables written to files, pipes or strings, as with `render` and `write_table`, are only limited by `.max_width(..)`, so captured output doesn't depend on the terminal the program ran in. Cells that no longer fit wrap onto more lines, or are truncated with `…` when using `.overflow(Overflow::Truncate)`.
+//!
+//! ### Borders
+//!
+//! Borders come from a `TableStyle`: pick `.style(TableStyle::rounded())` or one of the other presets (`classic`, `ascii_grid`, `unicode`, `double`, `markdown`, `minimal`, `borderless` and `compact`), or describe your own frame, header separator, row separators and column separators.
+//!
+//! ### Styling Cells
+//!
+//! Cells can be styled one at a time with `.cell_style(row, column, style)` (a red `FAILED` status), a row at a time with `.row_style(row, style)` (a dimmed, disabled entry), or all at once with `.body_style(style)`, and `.zebra(style)` stripes every other row. The most specific style wins, from cell to row to column to the body style.
+//!
+//! ### Ragged Rows
+//!
+//! Rows don't have to match the header: short rows are padded with empty cells and long rows add columns with empty headers. `Table::try_new` instead returns an error naming the first row with the wrong number of cells, `conform_rows(RaggedRows::Pad)` pads short rows and drops cells beyond the last header, and `Table::headerless(rows)` creates a table with no header row at all.
+//!
+//! ### Sorting and Grouping
+//!
+//! Rows can be rearranged before printing: `sort_by_column` and `sort_by_column_numeric` sort by a column in either `SortOrder`, `sort_by` takes a custom comparator, and `filter` keeps the rows matching a predicate. `group_by(column)` prints rows under a line for each value of a column, with `with_subtotals(columns)` summing numeric columns after each group. Each of these column-based methods has a `try_` variant, such as `try_group_by`, that returns an error for a column the table doesn't have.
+//!
+//! ### Footers and Spans
+//!
+//! A footer is printed below the rows with `.footer(cells)`, and `.aggregate(column, Aggregate::Sum)` fills a footer cell with the column's sum, average, count, minimum or maximum. Footer cells can span several columns with `.footer_span(column, columns)`, such as a `Total` label across the columns left of the amounts, and `with_span(row, column, columns)` does the same for ordinary cells. Spanned columns are widened until the spanning cell fits, and the cells it covers are not printed; `try_with_span` returns an error instead of hiding a cell that has contents.
+//!
+//! ### Exporting, Serde and Derive
+//!
+//! The same table can be exported with `to_markdown`, `to_csv`, `to_tsv`, `to_json` and `to_html`. The footer, with its aggregates computed, is exported as the last row (a `<tfoot>` in HTML), except by `to_json`, which lists only the rows; groups are not exported. With the `serde` feature, `Table::from_serialize(&deployments)` builds a table straight from a slice of `Serialize` structs, using field names as headers. With the `derive` feature, `#[derive(Tabled)]` does the same at compile time and `Table::from_tabled(&deployments)` lists the structs; `#[tabled(rename = "..", skip, align = "right", format = "path", order = 0)]` on a field renames, hides, aligns, formats or reorders its column.
+//!
+//! ### Streaming and Paging
+//!
+//! Large results don't have to be collected first: `print_table_stream(&template, rows, &options)` sizes the columns from the first `sample_size` rows (or from fixed `widths`) and prints every row as soon as the iterator yields it, repeating the header every `page_size` rows. `page_table(&table)` shows a table one screen at a time, waiting for Space to continue or `q` to stop, and always finishes with the footer and bottom border.
+//!
+//! ### Expanded Layout
+//!
+//! Tables with many columns can be printed as a list of records instead, like psql's `\x`: `.expanded(Expanded::On)` prints each row as a block of `header: value` lines under a `-[ RECORD n ]-` line, and `Expanded::Auto` does so only when the table is too wide for the terminal.
+//!
 //! ## Confirmations
 //!
 //! Use the `confirm` function to prompt the user with a yes/no question. The default answer is provided as a boolean.
 //!
 //! **Example:**
-//! ```rust
+//! ```rust,no_run
 //! use rustic_print::RusticPrint;
 //!
 //! let printer = RusticPrint::new();
@@ -67,7 +269,7 @@
 //! The `choice` function displays a list of options and lets the user pick one interactively.
 //!
 //! **Example:**
-//! ```rust
+//! ```rust,no_run
 //! use rustic_print::RusticPrint;
 //!
 //! let printer = RusticPrint::new();
@@ -108,6 +310,11 @@
 //! The following functions are available on the [`RusticPrint`] struct. Click any item for more details:
 //!
 //! - [`RusticPrint::new`] - Create a new `RusticPrint` instance.
+//! - [`RusticPrint::with_writer`] - Create a `RusticPrint` instance that writes all output to any writer.
+//! - [`RusticPrint::builder`] - Configure the writers, stream policy, theme and colors of a `RusticPrint` instance.
+//! - [`RusticPrint::into_inner`] - Consume the instance and return its main writer.
+//! - [`RusticPrint::into_writers`] - Consume the instance and return both of its writers.
+//! - [`RusticPrint::render`] - Render output into a `String`.
 //! - [`RusticPrint::block`] - Print a styled text block.
 //! - [`RusticPrint::underline_with_char`] - Underline a message with a repeated character.
 //! - [`RusticPrint::title`] - Display a title with a styled underline.
@@ -122,46 +329,280 @@
 //! - [`RusticPrint::listing`] - Display a list of items.
 //! - [`RusticPrint::text`] - Print wrapped text.
 //! - [`RusticPrint::table`] - Render a table with headers and rows.
+//! - [`RusticPrint::print_table`] - Print a table built with `Table::builder`.
+//! - [`RusticPrint::print_table_stream`] - Print a table as its rows arrive from an iterator.
+//! - [`RusticPrint::page_table`] - Show a table one screen at a time.
 //! - [`RusticPrint::confirm`] - Prompt for a yes/no confirmation.
 //! - [`RusticPrint::ask`] - Prompt for input with optional validation.
 //! - [`RusticPrint::choice`] - Present an interactive choice prompt.
 //!
+//! Each output and prompt function above also has a `try_` counterpart, such as [`RusticPrint::try_success`],
+//! that returns a `Result` instead of panicking.
+//!
 //! For more details on each function, please refer to the full [API Reference](https://docs.rs/rustic_print/latest/rustic_print/).
 
 pub mod block_options;
+pub mod builder;
+pub mod color;
+pub mod error;
 mod messages;
+pub mod streams;
 pub mod style_options;
 pub mod table;
+pub mod theme;
+pub mod width;
+
+pub use crate::error::Error;
 
 use crate::block_options::BlockOptions;
+use crate::builder::RusticPrintBuilder;
+use crate::color::{ColorChoice, ColorDepth, Painted, Painter};
 use crate::messages::Messages;
+use crate::streams::{Output, Stream, StreamPolicy};
 use crate::style_options::StyleOptions;
-use crate::table::Table;
+use crate::table::{StreamOptions, Table};
+use crate::theme::Theme;
+use crate::width::{display_width, padding, split_after_resets, track_sgr};
 use crossterm::event::KeyModifiers;
-use crossterm::style::{style, Print, PrintStyledContent};
+use crossterm::style::Print;
 use crossterm::{
+    cursor::MoveTo,
     event,
     event::{read, Event, KeyCode},
     queue,
-    style::{Color, ResetColor, SetBackgroundColor, SetForegroundColor, Stylize},
+    style::ResetColor,
     terminal,
-    terminal::{disable_raw_mode, enable_raw_mode},
+    terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType},
 };
-use std::error::Error;
-use std::io::{self, stdout, Write};
+use std::io::{self, stderr, Stderr, Stdout, Write};
+use std::sync::{Mutex, PoisonError};
 use std::time::Duration;
 use textwrap::{fill, Options};
 
-pub struct RusticPrint {}
+/// A closure used by [`RusticPrint::ask`] to validate user input.
+///
+/// It returns `Ok(())` if the input is valid, or an error message to display otherwise.
+pub type Validator = Box<dyn Fn(&str) -> Result<(), String>>;
+
+/// Renders styled blocks, prompts and tables to output writers.
+///
+/// By default, regular content is written to stdout while diagnostics (error, warning and caution
+/// blocks, plus interactive prompts) are written to stderr, so they never corrupt piped output.
+/// Use [`RusticPrint::with_writer`] to send everything to any other [`Write`] implementation,
+/// such as a file or a `Vec<u8>`, or [`RusticPrint::builder`] to configure each stream.
+///
+/// A `RusticPrint` is `Send` and `Sync` when its writers are `Send`, so it can be kept in a
+/// `static` or shared through an `Arc`. Each message is written while holding a lock on its
+/// writer, so messages printed from different threads are not interleaved.
+///
+/// # Example
+///
+/// ```rust
+/// use rustic_print::RusticPrint;
+/// use std::sync::{Arc, OnceLock};
+/// use std::thread;
+///
+/// static PRINTER: OnceLock<RusticPrint> = OnceLock::new();
+/// PRINTER.get_or_init(RusticPrint::new).info("Shared through a static.");
+///
+/// let printer = Arc::new(RusticPrint::with_writer(Vec::new()));
+/// let worker = Arc::clone(&printer);
+/// thread::spawn(move || worker.listing(vec!["from a thread"]))
+///     .join()
+///     .unwrap();
+///
+/// let output = Arc::into_inner(printer).unwrap().into_inner();
+/// assert_eq!(String::from_utf8(output).unwrap(), "* from a thread\n");
+/// ```
+pub struct RusticPrint<W: Write = Stdout, E: Write = Stderr> {
+    writer: Mutex<W>,
+    diagnostic_writer: Mutex<E>,
+    streams: StreamPolicy,
+    theme: Theme,
+    color_choice: ColorChoice,
+    color_depth: Option<ColorDepth>,
+    writer_is_terminal: bool,
+    diagnostic_writer_is_terminal: bool,
+}
 
 impl RusticPrint {
-    /// Creates a new instance of `RusticPrint`.
+    /// Creates a new instance of `RusticPrint` that writes content to stdout and diagnostics
+    /// to stderr.
     ///
     /// # Returns
     ///
     /// A new `RusticPrint` instance.
     pub fn new() -> RusticPrint {
-        RusticPrint {}
+        RusticPrint::builder().build()
+    }
+
+    /// Returns a builder for configuring the writers, stream policy and theme of a `RusticPrint`.
+    ///
+    /// # Returns
+    ///
+    /// A new `RusticPrintBuilder` instance.
+    pub fn builder() -> RusticPrintBuilder {
+        RusticPrintBuilder::new()
+    }
+}
+
+/// Returns a `RusticPrint` instance that writes content to stdout and diagnostics to stderr.
+impl Default for RusticPrint {
+    fn default() -> Self {
+        RusticPrint::new()
+    }
+}
+
+impl<W: Write> RusticPrint<W> {
+    /// Creates a new instance of `RusticPrint` that writes all output to the given writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination for all rendered output, including diagnostics.
+    ///
+    /// # Returns
+    ///
+    /// A new `RusticPrint` instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::RusticPrint;
+    ///
+    /// let printer = RusticPrint::with_writer(Vec::new());
+    /// printer.listing(vec!["apples", "pears"]);
+    ///
+    /// let output = String::from_utf8(printer.into_inner()).unwrap();
+    /// assert_eq!(output, "* apples\n* pears\n");
+    /// ```
+    pub fn with_writer(writer: W) -> RusticPrint<W> {
+        RusticPrint::builder()
+            .writer(writer)
+            .diagnostic_writer(stderr())
+            .streams(StreamPolicy::Single)
+            .build()
+    }
+}
+
+impl<W: Write, E: Write> RusticPrint<W, E> {
+    /// Consumes the `RusticPrint` instance and returns the main writer.
+    ///
+    /// # Returns
+    ///
+    /// The writer that received regular content.
+    pub fn into_inner(self) -> W {
+        self.writer
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Consumes the `RusticPrint` instance and returns both writers.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the main writer and the diagnostic writer.
+    pub fn into_writers(self) -> (W, E) {
+        (
+            self.writer
+                .into_inner()
+                .unwrap_or_else(PoisonError::into_inner),
+            self.diagnostic_writer
+                .into_inner()
+                .unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    /// Locks the writer that the given kind of output is routed to.
+    ///
+    /// A writer left poisoned by a panic elsewhere is still used, since a half-written line is no
+    /// reason to stop printing.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - The kind of output about to be written.
+    ///
+    /// # Returns
+    ///
+    /// The main writer, or the diagnostic writer for diagnostics under [`StreamPolicy::Split`].
+    fn output(&self, stream: Stream) -> Output<'_, W, E> {
+        if self.is_routed_to_diagnostic_writer(stream) {
+            Output::Diagnostic(
+                self.diagnostic_writer
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner),
+            )
+        } else {
+            Output::Main(self.writer.lock().unwrap_or_else(PoisonError::into_inner))
+        }
+    }
+
+    /// Creates a painter for the writer that the given kind of output is routed to.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - The kind of output about to be written.
+    ///
+    /// # Returns
+    ///
+    /// A `Painter` honoring the color choice, the color depth and whether that writer is a
+    /// terminal.
+    fn painter(&self, stream: Stream) -> Painter {
+        let is_terminal = if self.is_routed_to_diagnostic_writer(stream) {
+            self.diagnostic_writer_is_terminal
+        } else {
+            self.writer_is_terminal
+        };
+        Painter::new(
+            self.color_choice.should_colorize(is_terminal),
+            self.color_depth.unwrap_or_else(ColorDepth::detect),
+        )
+    }
+
+    /// Returns whether the given kind of output goes to the diagnostic writer.
+    fn is_routed_to_diagnostic_writer(&self, stream: Stream) -> bool {
+        stream == Stream::Diagnostic && self.streams == StreamPolicy::Split
+    }
+
+    /// Renders output into a `String` instead of writing it to this instance's writer.
+    ///
+    /// The closure receives a `RusticPrint` that writes into an in-memory buffer. Any of its
+    /// output primitives (blocks, titles, sections, listings, text and tables) can be called,
+    /// and everything they produce is returned as a single string. The buffer is not a terminal,
+    /// so the output is only styled if this instance uses `ColorChoice::Always`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure that renders output using the provided buffered `RusticPrint`.
+    ///
+    /// # Returns
+    ///
+    /// The rendered output as a `String`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::RusticPrint;
+    ///
+    /// let printer = RusticPrint::new();
+    /// let report = printer.render(|p| {
+    ///     p.listing(vec!["Build passed", "Tests passed"]);
+    /// });
+    ///
+    /// assert_eq!(report, "* Build passed\n* Tests passed\n");
+    /// ```
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: FnOnce(&RusticPrint<Vec<u8>>),
+    {
+        let buffer = RusticPrint::builder()
+            .writer(Vec::new())
+            .streams(StreamPolicy::Single)
+            .theme(self.theme.clone())
+            .color_choice(self.color_choice)
+            .color_depth(self.color_depth.unwrap_or_else(ColorDepth::detect))
+            .build();
+        f(&buffer);
+        String::from_utf8_lossy(&buffer.into_inner()).into_owned()
     }
 
     /// Prints a block of text using the provided messages and block options.
@@ -177,12 +618,26 @@ impl RusticPrint {
     /// # Panics
     ///
     /// Panics if rendering the block fails.
-    pub fn block<T>(&self, messages: T, block_options: BlockOptions) -> ()
+    pub fn block<T>(&self, messages: T, block_options: BlockOptions)
     where
         T: Into<Messages>,
     {
-        self.render_block(messages, block_options)
-            .expect("Failed to render b");
+        self.try_block(messages, block_options)
+            .expect("Failed to render block");
+    }
+
+    /// Prints a block of text, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::block`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_block<T>(&self, messages: T, block_options: BlockOptions) -> Result<(), Error>
+    where
+        T: Into<Messages>,
+    {
+        self.render_block(Stream::Content, messages, block_options)
     }
 
     /// Renders a text block with the specified messages and block options.
@@ -194,18 +649,28 @@ impl RusticPrint {
     ///
     /// # Arguments
     ///
+    /// * `stream` - The kind of output, deciding which writer the block is sent to.
     /// * `message` - The message content, convertible into `Messages`.
     /// * `block_options` - Options that define the block's styling, prefix, and padding.
     ///
     /// # Returns
     ///
     /// A `Result` indicating success or failure in rendering the block.
-    fn render_block<T>(&self, message: T, block_options: BlockOptions) -> Result<(), Box<dyn Error>>
+    fn render_block<T>(
+        &self,
+        stream: Stream,
+        message: T,
+        block_options: BlockOptions,
+    ) -> Result<(), Error>
     where
         T: Into<Messages>,
     {
         let message = message.into();
-        let mut stdout = stdout();
+        let mut out = self.output(stream);
+        let block_options = BlockOptions {
+            style: self.painter(stream).adapt(block_options.style.as_ref()),
+            ..block_options
+        };
 
         // Determine terminal width (default to 120 if unavailable) and cap the wrap width.
         let term_width = terminal::size().unwrap_or((120, 0)).0 as usize;
@@ -215,7 +680,7 @@ impl RusticPrint {
         }
 
         // Start with an empty line.
-        queue!(stdout, Print("\n"))?;
+        queue!(out, Print("\n"))?;
 
         // Prepare effective prefix (default to a single space if empty)
         let mut prefix = block_options.prefix.clone();
@@ -225,7 +690,7 @@ impl RusticPrint {
 
         // Print top padding if enabled.
         if block_options.padding {
-            print_padding_line(&mut stdout, wrap_width, &block_options, &prefix)?;
+            print_padding_line(&mut out, wrap_width, &block_options, &prefix)?;
         }
 
         // Prepare indent strings.
@@ -238,7 +703,7 @@ impl RusticPrint {
         let subsequent_indent = format!(
             "{}{}",
             prefix,
-            " ".repeat(initial_indent.len().saturating_sub(prefix.len()))
+            " ".repeat(display_width(&initial_indent).saturating_sub(display_width(&prefix)))
         );
 
         // Convert the message into a vector of strings.
@@ -249,7 +714,7 @@ impl RusticPrint {
 
         for (i, msg) in messages_vec.iter().enumerate() {
             if i > 0 {
-                print_padding_line(&mut stdout, wrap_width, &block_options, &prefix)?;
+                print_padding_line(&mut out, wrap_width, &block_options, &prefix)?;
             }
 
             // For the first message, use the full initial indent; for others, use the subsequent indent.
@@ -263,19 +728,33 @@ impl RusticPrint {
                     .subsequent_indent(&subsequent_indent)
             };
 
-            // Wrap and print each line of the message.
-            for line in fill(msg, &effective_options).lines() {
-                styled_print_line(&mut stdout, line, wrap_width, &block_options)?;
+            // Wrap and print each line of the message, carrying any styling embedded in the
+            // message over to the lines it wraps onto.
+            let mut active_sgr = String::new();
+            for (line_index, line) in fill(msg, &effective_options).lines().enumerate() {
+                let indent = if line_index == 0 {
+                    effective_options.initial_indent
+                } else {
+                    effective_options.subsequent_indent
+                };
+                let replayed = match line.strip_prefix(indent) {
+                    Some(text) if !active_sgr.is_empty() => {
+                        format!("{}{}{}", indent, active_sgr, text)
+                    }
+                    _ => line.to_string(),
+                };
+                track_sgr(line, &mut active_sgr);
+                styled_print_line(&mut out, &replayed, wrap_width, &block_options)?;
             }
         }
 
         // Print bottom padding if enabled.
         if block_options.padding {
-            print_padding_line(&mut stdout, wrap_width, &block_options, &prefix)?;
+            print_padding_line(&mut out, wrap_width, &block_options, &prefix)?;
         }
 
-        queue!(stdout, Print("\n"))?;
-        stdout.flush()?;
+        queue!(out, Print("\n"))?;
+        out.flush()?;
         Ok(())
     }
 
@@ -297,37 +776,24 @@ impl RusticPrint {
         message: &str,
         underline_char: char,
         style_options: Option<StyleOptions>,
-    ) -> Result<(), Box<dyn Error>> {
-        let mut stdout = stdout();
-
-        let mut message = style(message);
-        let mut underline = style(underline_char.to_string().repeat(message.to_string().len()));
-
-        if let Some(style_options) = style_options {
-            if let Some(foreground) = style_options.foreground {
-                message = message.with(foreground);
-                underline = underline.with(foreground);
-                queue!(stdout, SetForegroundColor(foreground))?;
-            }
-            if let Some(background) = style_options.background {
-                message = message.on(background);
-                underline = underline.on(background);
-                queue!(stdout, SetBackgroundColor(background))?;
-            }
-        }
+    ) -> Result<(), Error> {
+        let mut out = self.output(Stream::Content);
+        let style_options = self
+            .painter(Stream::Content)
+            .adapt(style_options.as_ref())
+            .unwrap_or_default();
+        let underline = underline_char.to_string().repeat(display_width(message));
 
         queue!(
-            stdout,
-            PrintStyledContent(message),
-            ResetColor,
+            out,
+            Print(Painted::new(Some(style_options.clone()), message)),
             Print("\n"),
-            PrintStyledContent(underline),
-            ResetColor,
+            Print(Painted::new(Some(style_options), underline)),
             Print("\n")
         )?;
 
-        queue!(stdout, Print("\n"))?;
-        stdout.flush()?;
+        queue!(out, Print("\n"))?;
+        out.flush()?;
 
         Ok(())
     }
@@ -347,49 +813,86 @@ impl RusticPrint {
         underline_char: char,
         style_options: Option<StyleOptions>,
     ) {
-        self.render_underline_with_char(message, underline_char, style_options)
+        self.try_underline_with_char(message, underline_char, style_options)
             .expect("Failed to render underline");
     }
 
-    /// Displays a title by underlining the provided message with '=' characters.
+    /// Underlines the given message, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::underline_with_char`] for details.
     ///
-    /// The title is styled with a dark green foreground.
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_underline_with_char(
+        &self,
+        message: &str,
+        underline_char: char,
+        style_options: Option<StyleOptions>,
+    ) -> Result<(), Error> {
+        self.render_underline_with_char(message, underline_char, style_options)
+    }
+
+    /// Displays a title by underlining the provided message, using the theme's `title` options.
+    ///
+    /// With the default theme, the title is underlined with '=' characters and styled with a dark
+    /// green foreground.
     ///
     /// # Arguments
     ///
     /// * `message` - The title text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if rendering the title fails.
     pub fn title(&self, message: &str) {
-        self.underline_with_char(
-            message,
-            '=',
-            Some(StyleOptions {
-                foreground: Some(Color::DarkGreen),
-                background: None,
-            }),
-        );
+        self.try_title(message).expect("Failed to render title");
     }
 
-    /// Displays a section header by underlining the message with '-' characters.
+    /// Displays a title, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::title`] for details.
     ///
-    /// The section header is styled with a dark green foreground.
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_title(&self, message: &str) -> Result<(), Error> {
+        let options = &self.theme.title;
+        self.render_underline_with_char(message, options.underline_char, options.style.clone())
+    }
+
+    /// Displays a section header by underlining the message, using the theme's `section` options.
+    ///
+    /// With the default theme, the header is underlined with '-' characters and styled with a
+    /// dark green foreground.
     ///
     /// # Arguments
     ///
     /// * `message` - The section header text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if rendering the section header fails.
     pub fn section(&self, message: &str) {
-        self.underline_with_char(
-            message,
-            '-',
-            Some(StyleOptions {
-                foreground: Some(Color::DarkGreen),
-                background: None,
-            }),
-        );
+        self.try_section(message).expect("Failed to render section");
+    }
+
+    /// Displays a section header, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::section`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_section(&self, message: &str) -> Result<(), Error> {
+        let options = &self.theme.section;
+        self.render_underline_with_char(message, options.underline_char, options.style.clone())
     }
 
-    /// Prints a success block with black text on a green background.
+    /// Prints a success block using the theme's `success` options.
     ///
-    /// The block is labeled with "OK" and includes padding.
+    /// With the default theme, the block has black text on a green background, is labeled with
+    /// "OK" and includes padding.
     ///
     /// # Arguments
     ///
@@ -402,24 +905,29 @@ impl RusticPrint {
     where
         T: Into<Messages>,
     {
-        self.render_block(
-            messages,
-            BlockOptions {
-                style: Some(StyleOptions {
-                    foreground: Some(Color::Black),
-                    background: Some(Color::DarkGreen),
-                }),
-                block_type: Some("OK".to_string()),
-                padding: true,
-                ..Default::default()
-            },
-        )
-        .expect("Failed to print success block");
+        self.try_success(messages)
+            .expect("Failed to print success block");
     }
 
-    /// Prints a caution block with grey text on a dark red background.
+    /// Prints a success block, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::success`] for details.
     ///
-    /// The block is labeled "CAUTION" and uses a custom prefix along with padding.
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_success<T>(&self, messages: T) -> Result<(), Error>
+    where
+        T: Into<Messages>,
+    {
+        self.render_block(Stream::Content, messages, self.theme.success.clone())
+    }
+
+    /// Prints a caution block using the theme's `caution` options.
+    ///
+    /// With the default theme, the block has grey text on a dark red background, is labeled
+    /// "CAUTION" and uses a custom prefix along with padding.
+    /// It is a diagnostic, so it is sent to the diagnostic writer (stderr by default).
     ///
     /// # Arguments
     ///
@@ -432,25 +940,29 @@ impl RusticPrint {
     where
         T: Into<Messages>,
     {
-        self.render_block(
-            messages,
-            BlockOptions {
-                style: Some(StyleOptions {
-                    foreground: Some(Color::Grey),
-                    background: Some(Color::DarkRed),
-                }),
-                block_type: Some("CAUTION".to_string()),
-                prefix: " ! ".to_string(),
-                padding: true,
-                ..Default::default()
-            },
-        )
-        .expect("Failed to print caution block");
+        self.try_caution(messages)
+            .expect("Failed to print caution block");
     }
 
-    /// Prints an error block with grey text on a dark red background.
+    /// Prints a caution block, returning an error instead of panicking if rendering fails.
     ///
-    /// The block is labeled "ERROR" and includes padding.
+    /// See [`RusticPrint::caution`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_caution<T>(&self, messages: T) -> Result<(), Error>
+    where
+        T: Into<Messages>,
+    {
+        self.render_block(Stream::Diagnostic, messages, self.theme.caution.clone())
+    }
+
+    /// Prints an error block using the theme's `error` options.
+    ///
+    /// With the default theme, the block has grey text on a dark red background, is labeled
+    /// "ERROR" and includes padding.
+    /// It is a diagnostic, so it is sent to the diagnostic writer (stderr by default).
     ///
     /// # Arguments
     ///
@@ -463,25 +975,28 @@ impl RusticPrint {
     where
         T: Into<Messages>,
     {
-        self.render_block(
-            messages,
-            BlockOptions {
-                style: Some(StyleOptions {
-                    foreground: Some(Color::Grey),
-                    background: Some(Color::DarkRed),
-                }),
-                block_type: Some("ERROR".to_string()),
-                prefix: " ".to_string(),
-                padding: true,
-                ..Default::default()
-            },
-        )
-        .expect("Failed to print error block");
+        self.try_error(messages)
+            .expect("Failed to print error block");
+    }
+
+    /// Prints an error block, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::error`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_error<T>(&self, messages: T) -> Result<(), Error>
+    where
+        T: Into<Messages>,
+    {
+        self.render_block(Stream::Diagnostic, messages, self.theme.error.clone())
     }
 
-    /// Prints a comment block prefixed with "//".
+    /// Prints a comment block using the theme's `comment` options.
     ///
-    /// Useful for displaying comments or annotations.
+    /// Useful for displaying comments or annotations. With the default theme, each line is
+    /// prefixed with "//".
     ///
     /// # Arguments
     ///
@@ -494,19 +1009,29 @@ impl RusticPrint {
     where
         T: Into<Messages>,
     {
-        self.render_block(
-            messages,
-            BlockOptions {
-                prefix: " // ".to_string(),
-                ..Default::default()
-            },
-        )
-        .expect("Failed to print comment block");
+        self.try_comment(messages)
+            .expect("Failed to print comment block");
+    }
+
+    /// Prints a comment block, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::comment`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_comment<T>(&self, messages: T) -> Result<(), Error>
+    where
+        T: Into<Messages>,
+    {
+        self.render_block(Stream::Content, messages, self.theme.comment.clone())
     }
 
-    /// Prints a warning block with black text on a dark yellow background.
+    /// Prints a warning block using the theme's `warning` options.
     ///
-    /// The block is labeled "WARNING" and includes padding.
+    /// With the default theme, the block has black text on a dark yellow background, is labeled
+    /// "WARNING" and includes padding.
+    /// It is a diagnostic, so it is sent to the diagnostic writer (stderr by default).
     ///
     /// # Arguments
     ///
@@ -519,24 +1044,27 @@ impl RusticPrint {
     where
         T: Into<Messages>,
     {
-        self.render_block(
-            messages,
-            BlockOptions {
-                style: Some(StyleOptions {
-                    foreground: Some(Color::Black),
-                    background: Some(Color::DarkYellow),
-                }),
-                block_type: Some("WARNING".to_string()),
-                padding: true,
-                ..Default::default()
-            },
-        )
-        .expect("Failed to print comment block");
+        self.try_warning(messages)
+            .expect("Failed to print warning block");
+    }
+
+    /// Prints a warning block, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::warning`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_warning<T>(&self, messages: T) -> Result<(), Error>
+    where
+        T: Into<Messages>,
+    {
+        self.render_block(Stream::Diagnostic, messages, self.theme.warning.clone())
     }
 
-    /// Prints an informational block with green text.
+    /// Prints an informational block using the theme's `info` options.
     ///
-    /// The block is labeled "INFO" and includes padding.
+    /// With the default theme, the block has green text, is labeled "INFO" and includes padding.
     ///
     /// # Arguments
     ///
@@ -549,24 +1077,27 @@ impl RusticPrint {
     where
         T: Into<Messages>,
     {
-        self.render_block(
-            messages,
-            BlockOptions {
-                style: Some(StyleOptions {
-                    foreground: Some(Color::Green),
-                    background: None,
-                }),
-                block_type: Some("INFO".to_string()),
-                padding: true,
-                ..Default::default()
-            },
-        )
-        .expect("Failed to print info block");
+        self.try_info(messages).expect("Failed to print info block");
+    }
+
+    /// Prints an informational block, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::info`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_info<T>(&self, messages: T) -> Result<(), Error>
+    where
+        T: Into<Messages>,
+    {
+        self.render_block(Stream::Content, messages, self.theme.info.clone())
     }
 
-    /// Prints a note block with dark yellow text.
+    /// Prints a note block using the theme's `note` options.
     ///
-    /// The block is labeled "NOTE", uses a custom prefix, and does not include padding.
+    /// With the default theme, the block has dark yellow text, is labeled "NOTE", uses a custom
+    /// prefix, and does not include padding.
     ///
     /// # Arguments
     ///
@@ -579,19 +1110,21 @@ impl RusticPrint {
     where
         T: Into<Messages>,
     {
-        self.render_block(
-            messages,
-            BlockOptions {
-                style: Some(StyleOptions {
-                    foreground: Some(Color::DarkYellow),
-                    background: None,
-                }),
-                block_type: Some("NOTE".to_string()),
-                prefix: " ! ".to_string(),
-                ..Default::default()
-            },
-        )
-        .expect("Failed to print info block");
+        self.try_note(messages).expect("Failed to print note block");
+    }
+
+    /// Prints a note block, returning an error instead of panicking if rendering fails.
+    ///
+    /// See [`RusticPrint::note`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_note<T>(&self, messages: T) -> Result<(), Error>
+    where
+        T: Into<Messages>,
+    {
+        self.render_block(Stream::Content, messages, self.theme.note.clone())
     }
 
     /// Displays a list of items, each preceded by an asterisk.
@@ -607,12 +1140,27 @@ impl RusticPrint {
     where
         T: std::fmt::Display,
     {
-        let mut stdout = stdout();
-        for (_, item) in items.iter().enumerate() {
-            queue!(stdout, Print(format!("* {}\n", item))).expect("Failed to queue print");
+        self.try_listing(items).expect("Failed to print listing");
+    }
+
+    /// Displays a list of items, returning an error instead of panicking if printing fails.
+    ///
+    /// See [`RusticPrint::listing`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_listing<T>(&self, items: Vec<T>) -> Result<(), Error>
+    where
+        T: std::fmt::Display,
+    {
+        let mut out = self.output(Stream::Content);
+        for item in items.iter() {
+            queue!(out, Print(format!("* {}\n", item)))?;
         }
 
-        stdout.flush().expect("Failed to flush stdout");
+        out.flush()?;
+        Ok(())
     }
 
     /// Prints text with automatic wrapping based on the terminal width.
@@ -630,31 +1178,298 @@ impl RusticPrint {
     where
         T: std::fmt::Display,
     {
-        let mut stdout = stdout();
+        self.try_text(message).expect("Failed to print text");
+    }
+
+    /// Prints wrapped text, returning an error instead of panicking if printing fails.
+    ///
+    /// See [`RusticPrint::text`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_text<T>(&self, message: T) -> Result<(), Error>
+    where
+        T: std::fmt::Display,
+    {
+        let mut out = self.output(Stream::Content);
 
         let term_width = terminal::size().unwrap_or((120, 0)).0 as usize;
-        let options = Options::new(term_width.clone() - 1)
+        let options = Options::new(term_width - 1)
             .initial_indent(" ")
             .subsequent_indent(" ");
 
-        for line in fill(&*message.to_string(), options).lines() {
-            queue!(stdout, Print(line), Print("\n")).expect("Failed to queue print");
+        for line in fill(&message.to_string(), options).lines() {
+            queue!(out, Print(line), Print("\n"))?;
         }
 
-        stdout.flush().expect("Failed to flush stdout");
+        out.flush()?;
+        Ok(())
     }
 
     /// Prints a table with the specified headers and rows.
     ///
-    /// Internally, this function creates a `Table` instance and calls its `print_table` method.
+    /// Internally, this function creates a `Table` instance and calls its `write_table` method.
     ///
     /// # Arguments
     ///
     /// * `headers` - A vector of string slices representing the table headers.
     /// * `rows` - A vector of rows, where each row is a vector of string slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing the table fails.
     pub fn table(&self, headers: Vec<&str>, rows: Vec<Vec<&str>>) {
-        let table = Table::new(headers, rows);
-        table.print_table();
+        self.try_table(headers, rows)
+            .expect("Failed to print table");
+    }
+
+    /// Prints a table, returning an error instead of panicking if writing fails.
+    ///
+    /// See [`RusticPrint::table`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_table(&self, headers: Vec<&str>, rows: Vec<Vec<&str>>) -> Result<(), Error> {
+        self.try_print_table(&Table::new(headers, rows))
+    }
+
+    /// Prints a table built with [`Table::builder`] or [`Table::new`].
+    ///
+    /// The table is styled like the output of [`RusticPrint::table`]: its header uses the
+    /// theme's `table.header` style unless the table sets its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The table to print.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing the table fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    /// use rustic_print::RusticPrint;
+    ///
+    /// let table = Table::builder()
+    ///     .header(["Service", "Uptime (days)"])
+    ///     .row(["api".to_string(), 42.to_string()])
+    ///     .row(["worker".to_string(), 7.to_string()])
+    ///     .build();
+    ///
+    /// let printer = RusticPrint::new();
+    /// printer.print_table(&table);
+    /// ```
+    pub fn print_table(&self, table: &Table) {
+        self.try_print_table(table).expect("Failed to print table");
+    }
+
+    /// Prints a table, returning an error instead of panicking if writing fails.
+    ///
+    /// See [`RusticPrint::print_table`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_print_table(&self, table: &Table) -> Result<(), Error> {
+        table.write_styled(
+            &mut self.output(Stream::Content),
+            &self.painter(Stream::Content),
+            &self.theme.table.header,
+            self.writer_is_terminal,
+        )?;
+        Ok(())
+    }
+
+    /// Prints a table whose rows arrive one at a time, such as from a database cursor.
+    ///
+    /// The table is a template whose headers, column options and style apply; see
+    /// [`Table::write_stream`] for how columns are sized and pages are printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The table to use as a template.
+    /// * `rows` - The rows to print, each an iterator of cells.
+    /// * `options` - How columns are sized and how often the header is repeated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to the output fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{StreamOptions, Table};
+    /// use rustic_print::RusticPrint;
+    ///
+    /// let printer = RusticPrint::new();
+    /// let template = Table::new(vec!["Id", "Event"], Vec::<Vec<String>>::new());
+    /// let events = (1..=200).map(|id| vec![id.to_string(), format!("event {}", id)]);
+    ///
+    /// printer.print_table_stream(
+    ///     &template,
+    ///     events,
+    ///     &StreamOptions {
+    ///         page_size: Some(50),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// ```
+    pub fn print_table_stream<I, R, T>(&self, table: &Table, rows: I, options: &StreamOptions)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: std::fmt::Display,
+    {
+        self.try_print_table_stream(table, rows, options)
+            .expect("Failed to print table");
+    }
+
+    /// Prints a table whose rows arrive one at a time, returning an error instead of panicking
+    /// if writing fails.
+    ///
+    /// See [`RusticPrint::print_table_stream`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the output fails.
+    pub fn try_print_table_stream<I, R, T>(
+        &self,
+        table: &Table,
+        rows: I,
+        options: &StreamOptions,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: std::fmt::Display,
+    {
+        table.write_pages(
+            &mut self.output(Stream::Content),
+            rows,
+            options,
+            &self.painter(Stream::Content),
+            &self.theme.table.header,
+            self.writer_is_terminal,
+        )?;
+        Ok(())
+    }
+
+    /// Shows a table one screen at a time, like `more`.
+    ///
+    /// The table is rendered in full, with its groups, footer and expanded layout, and shown a
+    /// screenful of lines at a time, so rows that span several lines are paged correctly. After
+    /// each page, a prompt waits for Space or Enter to show the next page, with the header
+    /// printed again, or `q` or Esc to stop. The footer and bottom border are printed either
+    /// way. If the output is not a terminal, or the whole table fits, it is printed as with
+    /// [`RusticPrint::print_table`].
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The table to show.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing the table or reading input fails. If the user presses Ctrl+C, the
+    /// process exits instead.
+    pub fn page_table(&self, table: &Table) {
+        match self.try_page_table(table) {
+            Ok(()) => {}
+            Err(Error::Cancelled) => std::process::exit(0),
+            Err(err) => panic!("Failed to page table: {}", err),
+        }
+    }
+
+    /// Shows a table one screen at a time, returning an error instead of panicking.
+    ///
+    /// See [`RusticPrint::page_table`] for details.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::TerminalUnavailable`] if raw mode cannot be enabled, e.g. without a TTY.
+    /// - [`Error::Cancelled`] if the user presses Ctrl+C.
+    /// - [`Error::Io`] if writing the table or reading input fails.
+    pub fn try_page_table(&self, table: &Table) -> Result<(), Error> {
+        let painter = self.painter(Stream::Content);
+        let [header, body, trailer] =
+            table.render_sections(&painter, &self.theme.table.header, self.writer_is_terminal);
+        let lines: Vec<&str> = body.split_inclusive('\n').collect();
+        let header_height = header.lines().count();
+        let trailer_height = trailer.lines().count();
+
+        // Leave room for the header block, the trailer and the prompt.
+        let page_size = terminal::size()
+            .ok()
+            .map(|(_, rows)| rows as usize)
+            .filter(|&rows| {
+                self.writer_is_terminal && header_height + lines.len() + trailer_height >= rows
+            })
+            .map(|rows| {
+                rows.saturating_sub(header_height + trailer_height + 1)
+                    .max(1)
+            });
+
+        let mut out = self.output(Stream::Content);
+        let Some(page_size) = page_size else {
+            write!(out, "{}{}{}", header, body, trailer)?;
+            out.flush()?;
+            return Ok(());
+        };
+
+        let total = lines.len();
+        for (page, chunk) in lines.chunks(page_size).enumerate() {
+            if page > 0 {
+                let written = page * page_size;
+                let prompt = format!(
+                    "-- More ({} of {} lines) -- [space] next page, [q] quit",
+                    written, total
+                );
+                write!(
+                    out,
+                    "{}",
+                    painter.paint(&self.theme.prompt.question, prompt)
+                )?;
+                out.flush()?;
+
+                let raw_mode = RawMode::enable()?;
+                let keep_going = loop {
+                    let Event::Key(key_event) = read()? else {
+                        continue;
+                    };
+                    match key_event.code {
+                        KeyCode::Char('c')
+                            if key_event.modifiers.contains(KeyModifiers::CONTROL) =>
+                        {
+                            return Err(Error::Cancelled);
+                        }
+                        KeyCode::Char(' ') | KeyCode::Enter | KeyCode::PageDown => break true,
+                        KeyCode::Char('q') | KeyCode::Esc => break false,
+                        _ => {}
+                    }
+                };
+                drop(raw_mode);
+
+                // Erase the prompt so the next page continues the table.
+                queue!(out, Print("\r"), Clear(ClearType::CurrentLine))?;
+                if !keep_going {
+                    break;
+                }
+            }
+
+            // Start every page with the header, so each screenful reads as a table of its own.
+            write!(out, "{}", header)?;
+            for line in chunk {
+                write!(out, "{}", line)?;
+            }
+            out.flush()?;
+        }
+
+        write!(out, "{}", trailer)?;
+        out.flush()?;
+        Ok(())
     }
 
     /// Prompts the user for confirmation with a yes/no question.
@@ -670,55 +1485,77 @@ impl RusticPrint {
     /// # Returns
     ///
     /// Returns `true` if the user confirms (yes), otherwise `false`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the terminal is unavailable or reading input fails. If the user presses
+    /// Ctrl+C, the process exits instead.
     pub fn confirm(&self, question: &str, default: bool) -> bool {
-        let mut stdout = io::stdout();
-        enable_raw_mode().expect("Failed to enable raw mode");
+        match self.try_confirm(question, default) {
+            Ok(answer) => answer,
+            Err(Error::Cancelled) => std::process::exit(0),
+            Err(err) => panic!("Failed to read confirmation: {}", err),
+        }
+    }
+
+    /// Prompts the user for confirmation, returning an error instead of panicking.
+    ///
+    /// See [`RusticPrint::confirm`] for details.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::TerminalUnavailable`] if raw mode cannot be enabled, e.g. without a TTY.
+    /// - [`Error::Cancelled`] if the user presses Ctrl+C.
+    /// - [`Error::Io`] if reading input or writing the prompt fails.
+    pub fn try_confirm(&self, question: &str, default: bool) -> Result<bool, Error> {
+        let mut out = self.output(Stream::Diagnostic);
+        let painter = self.painter(Stream::Diagnostic);
+        let raw_mode = RawMode::enable()?;
 
         let default_answer = if default { "yes" } else { "no" };
 
-        // Using the Stylize trait to color the prompt.
-        print!(
+        write!(
+            out,
             "{} (yes/no) [{}]:\r\n > ",
-            question.green(),
-            default_answer.yellow()
-        );
-        stdout.flush().expect("Failed to flush stdout");
+            painter.paint(&self.theme.prompt.question, question),
+            painter.paint(&self.theme.prompt.default_value, default_answer)
+        )?;
+        out.flush()?;
 
         let mut input = String::new();
 
         loop {
-            if let Event::Key(key_event) = read().expect("Failed to read event") {
+            if let Event::Key(key_event) = read()? {
                 match key_event.code {
+                    KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                        return Err(Error::Cancelled);
+                    }
                     KeyCode::Char(c) => {
-                        print!("{}", c);
+                        write!(out, "{}", c)?;
                         input.push(c);
                     }
                     KeyCode::Enter => {
-                        println!();
+                        writeln!(out)?;
                         break;
                     }
-                    KeyCode::Backspace => {
-                        if !input.is_empty() {
-                            input.pop();
-                            print!("\x08 \x08"); // Visual backspace.
-                        }
+                    KeyCode::Backspace if !input.is_empty() => {
+                        input.pop();
+                        write!(out, "\x08 \x08")?; // Visual backspace.
                     }
                     _ => {}
                 }
-                stdout.flush().expect("Failed to flush stdout");
+                out.flush()?;
             }
         }
 
-        disable_raw_mode().expect("Failed to disable raw mode");
-        println!();
+        drop(raw_mode);
+        writeln!(out)?;
 
-        if input.trim().is_empty() {
-            default
-        } else if input.trim().eq_ignore_ascii_case("yes") || input.trim().eq_ignore_ascii_case("y")
-        {
-            true
+        let input = input.trim();
+        if input.is_empty() {
+            Ok(default)
         } else {
-            false
+            Ok(input.eq_ignore_ascii_case("yes") || input.eq_ignore_ascii_case("y"))
         }
     }
 
@@ -740,23 +1577,40 @@ impl RusticPrint {
     ///
     /// # Panics
     ///
-    /// Panics if reading from stdin fails.
+    /// Panics if reading from stdin fails, or if stdin is closed before a valid answer is given.
     pub fn ask(
         &self,
         question: &str,
         default: Option<&str>,
-        validator: Option<Box<dyn Fn(&str) -> Result<(), String>>>,
+        validator: Option<Validator>,
     ) -> String {
-        let mut stdout = io::stdout();
+        self.try_ask(question, default, validator)
+            .expect("Failed to read answer")
+    }
+
+    /// Prompts the user with a question, returning an error instead of panicking.
+    ///
+    /// See [`RusticPrint::ask`] for details.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Io`] if reading from stdin or writing the prompt fails.
+    /// - [`Error::InvalidInput`] if stdin is closed and the last answer failed validation.
+    pub fn try_ask(
+        &self,
+        question: &str,
+        default: Option<&str>,
+        validator: Option<Validator>,
+    ) -> Result<String, Error> {
+        let mut out = self.output(Stream::Diagnostic);
+        let painter = self.painter(Stream::Diagnostic);
 
         loop {
-            Self::ask_question(question, default);
-            stdout.flush().expect("Failed to flush stdout");
+            self.ask_question(&mut out, question, default)?;
+            out.flush()?;
 
             let mut input = String::new();
-            io::stdin()
-                .read_line(&mut input)
-                .expect("Failed to read line");
+            let bytes_read = io::stdin().read_line(&mut input)?;
             let input = input.trim();
 
             // Use the default value if the user provides no input.
@@ -769,13 +1623,19 @@ impl RusticPrint {
             // Validate the answer if a validator was provided.
             if let Some(ref validate) = validator {
                 match validate(&answer) {
-                    Ok(_) => return answer,
+                    Ok(_) => return Ok(answer),
+                    // Stdin is closed, so asking again would loop forever.
+                    Err(err) if bytes_read == 0 => return Err(Error::InvalidInput(err)),
                     Err(err) => {
-                        println!("{}", err.red());
+                        writeln!(
+                            out,
+                            "{}",
+                            painter.paint(&self.theme.prompt.validation_error, err)
+                        )?;
                     }
                 }
             } else {
-                return answer;
+                return Ok(answer);
             }
         }
     }
@@ -786,16 +1646,31 @@ impl RusticPrint {
     ///
     /// # Arguments
     ///
+    /// * `out` - The writer to display the prompt on.
     /// * `question` - The question to display.
     /// * `default_text` - An optional default text to show.
-    fn ask_question(question: &str, default_text: Option<&str>) {
+    fn ask_question(
+        &self,
+        out: &mut impl Write,
+        question: &str,
+        default_text: Option<&str>,
+    ) -> io::Result<()> {
+        let painter = self.painter(Stream::Diagnostic);
         let default_text = if let Some(dt) = default_text {
-            format!(" [{}]", dt.dark_green())
+            format!(
+                " [{}]",
+                painter.paint(&self.theme.prompt.input_default_value, dt)
+            )
         } else {
             String::new()
         };
 
-        print!("{}{}:\n> ", question.dark_green(), default_text);
+        write!(
+            out,
+            "{}{}:\n> ",
+            painter.paint(&self.theme.prompt.input_question, question),
+            default_text
+        )
     }
 
     /// Presents a multiple-choice question to the user and returns the selected option.
@@ -815,189 +1690,255 @@ impl RusticPrint {
     ///
     /// # Panics
     ///
-    /// Panics if reading events or flushing output fails.
+    /// Panics if `choices` is empty, the terminal is unavailable, or reading events or flushing
+    /// output fails. If the user presses Ctrl+C, the process exits instead.
     pub fn choice(&self, question: &str, choices: &[&str], default: Option<&str>) -> String {
+        match self.try_choice(question, choices, default) {
+            Ok(choice) => choice,
+            Err(Error::Cancelled) => std::process::exit(0),
+            Err(err) => panic!("Failed to read choice: {}", err),
+        }
+    }
+
+    /// Presents a multiple-choice question, returning an error instead of panicking.
+    ///
+    /// See [`RusticPrint::choice`] for details.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidInput`] if `choices` is empty.
+    /// - [`Error::TerminalUnavailable`] if the cursor cannot be queried or raw mode cannot be
+    ///   enabled, e.g. without a TTY.
+    /// - [`Error::Cancelled`] if the user presses Ctrl+C.
+    /// - [`Error::Io`] if reading events or writing the prompt fails.
+    pub fn try_choice(
+        &self,
+        question: &str,
+        choices: &[&str],
+        default: Option<&str>,
+    ) -> Result<String, Error> {
+        if choices.is_empty() {
+            return Err(Error::InvalidInput(
+                "at least one choice is required".to_string(),
+            ));
+        }
+
         loop {
-            // Re-render the entire question block.
-            let mut stdout = stdout();
-            if default.is_some() {
-                print!("{} [{}]:\n", question.green(), default.unwrap().green());
-            } else {
-                print!("{}:\n", question.green());
+            let (final_choice, input_buffer) = self.read_choice(question, choices, default)?;
+            if !final_choice.is_empty() {
+                return Ok(final_choice.to_string());
             }
-            for (i, choice) in choices.iter().enumerate() {
-                println!("  [{}] {}", i.to_string().green(), choice);
-            }
-            // Print the prompt line.
-            print!("> ");
-            stdout.flush().unwrap();
-            // Save the current cursor row for the prompt.
-            let (_, prompt_row) = crossterm::cursor::position().unwrap();
-
-            // Enable raw mode for interactive input.
-            enable_raw_mode().expect("Failed to enable raw mode");
-
-            // Initialize the input buffer and selection.
-            let mut input_buffer = String::new();
-            // If the default text exactly matches one of the choices, use its index; otherwise, default to 0.
-            let mut selected_index = choices
-                .iter()
-                .position(|&c| c == default.unwrap_or(""))
-                .unwrap_or(0);
-
-            // Immediately display the default selection.
-            if default.is_some() {
-                print!("{}", choices[selected_index]);
+
+            // If the selection is invalid, render an error block and restart.
+            self.try_error(format!(
+                "Invalid selection: \"{}\". Please enter a valid index or choice.",
+                input_buffer
+            ))?;
+        }
+    }
+
+    /// Renders the choice prompt once and reads the user's selection.
+    ///
+    /// This is a helper function for the `choice` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `question` - The prompt question.
+    /// * `choices` - A non-empty slice of choices to select from.
+    /// * `default` - An optional default choice.
+    ///
+    /// # Returns
+    ///
+    /// The matching choice (empty if the input matched nothing) and the raw input buffer.
+    fn read_choice<'c>(
+        &self,
+        question: &str,
+        choices: &[&'c str],
+        default: Option<&str>,
+    ) -> Result<(&'c str, String), Error> {
+        let mut out = self.output(Stream::Diagnostic);
+        let painter = self.painter(Stream::Diagnostic);
+
+        // Re-render the entire question block.
+        if let Some(default) = default {
+            writeln!(
+                out,
+                "{} [{}]:",
+                painter.paint(&self.theme.prompt.question, question),
+                painter.paint(&self.theme.prompt.choice_default_value, default)
+            )?;
+        } else {
+            writeln!(
+                out,
+                "{}:",
+                painter.paint(&self.theme.prompt.question, question)
+            )?;
+        }
+        for (i, choice) in choices.iter().enumerate() {
+            writeln!(
+                out,
+                "  [{}] {}",
+                painter.paint(&self.theme.prompt.choice_index, i),
+                choice
+            )?;
+        }
+        // Print the prompt line.
+        write!(out, "> ")?;
+        out.flush()?;
+        // Save the current cursor row for the prompt.
+        let (_, prompt_row) = crossterm::cursor::position().map_err(Error::TerminalUnavailable)?;
+
+        // Enable raw mode for interactive input.
+        let raw_mode = RawMode::enable()?;
+
+        // Initialize the input buffer and selection.
+        let mut input_buffer = String::new();
+        // If the default text exactly matches one of the choices, use its index; otherwise, default to 0.
+        let mut selected_index = choices
+            .iter()
+            .position(|&c| c == default.unwrap_or(""))
+            .unwrap_or(0);
+
+        // Immediately display the default selection.
+        if default.is_some() {
+            write!(out, "{}", choices[selected_index])?;
+        }
+
+        out.flush()?;
+
+        // Process key events.
+        loop {
+            if !event::poll(Duration::from_millis(500))? {
+                continue;
             }
+            let Event::Key(key_event) = event::read()? else {
+                continue;
+            };
 
-            stdout.flush().unwrap();
-
-            // Inner loop: process key events.
-            loop {
-                if event::poll(Duration::from_millis(500)).unwrap() {
-                    match event::read().unwrap() {
-                        Event::Key(key_event) => {
-                            match key_event.code {
-                                KeyCode::Enter => break,
-                                // Tab completes the suggestion.
-                                KeyCode::Tab => {
-                                    input_buffer = choices[selected_index].to_string();
-                                }
-                                KeyCode::Up => {
-                                    selected_index = if selected_index == 0 {
-                                        choices.len() - 1
-                                    } else {
-                                        selected_index - 1
-                                    };
-                                    input_buffer.clear();
-                                }
-                                KeyCode::Down => {
-                                    selected_index = (selected_index + 1) % choices.len();
-                                    input_buffer.clear();
-                                }
-                                KeyCode::Char(c) => {
-                                    if c == 'c'
-                                        && key_event.modifiers.contains(KeyModifiers::CONTROL)
-                                    {
-                                        disable_raw_mode().unwrap();
-                                        std::process::exit(0);
-                                    }
-
-                                    input_buffer.push(c);
-
-                                    // If the buffer parses as a valid index, update selection.
-                                    if let Ok(idx) = input_buffer.parse::<usize>() {
-                                        if idx < choices.len() {
-                                            selected_index = idx;
-                                        }
-                                    }
-                                    // Otherwise, if the text starts any choice (case-insensitive), update selection.
-                                    for (i, &choice) in choices.iter().enumerate() {
-                                        if choice
-                                            .to_lowercase()
-                                            .starts_with(&input_buffer.to_lowercase())
-                                        {
-                                            selected_index = i;
-                                            break;
-                                        }
-                                    }
-                                }
-                                KeyCode::Backspace => {
-                                    input_buffer.pop();
-                                }
-                                _ => {}
-                            }
-                            // Update the prompt line.
-                            use crossterm::{
-                                cursor::MoveTo,
-                                queue,
-                                style::{
-                                    Color, ResetColor, SetBackgroundColor, SetForegroundColor,
-                                },
-                                terminal::{Clear, ClearType},
-                            };
-                            queue!(
-                                stdout,
-                                MoveTo(2, prompt_row),
-                                Clear(ClearType::UntilNewLine)
-                            )
-                            .unwrap();
-
-                            if input_buffer.is_empty() {
-                                // If nothing has been typed, display the full default/suggestion in normal style.
-                                print!("{}", choices[selected_index]);
-                            } else {
-                                let suggestion = choices[selected_index];
-                                // If the suggestion begins with the user's input (case-insensitive)
-                                if suggestion
-                                    .to_lowercase()
-                                    .starts_with(&input_buffer.to_lowercase())
-                                {
-                                    let remainder = &suggestion[input_buffer.len()..];
-                                    // Print the user's input as usual.
-                                    print!("{}", input_buffer);
-                                    // Print the remainder with grey background and white foreground.
-                                    queue!(
-                                        stdout,
-                                        SetForegroundColor(Color::White),
-                                        SetBackgroundColor(Color::Grey)
-                                    )
-                                    .unwrap();
-                                    print!("{}", remainder);
-                                    // Reset styling.
-                                    queue!(stdout, ResetColor).unwrap();
-                                } else {
-                                    print!("{}", input_buffer);
-                                }
-                            }
-                            stdout.flush().unwrap();
+            match key_event.code {
+                KeyCode::Enter => break,
+                // Tab completes the suggestion.
+                KeyCode::Tab => {
+                    input_buffer = choices[selected_index].to_string();
+                }
+                KeyCode::Up => {
+                    selected_index = if selected_index == 0 {
+                        choices.len() - 1
+                    } else {
+                        selected_index - 1
+                    };
+                    input_buffer.clear();
+                }
+                KeyCode::Down => {
+                    selected_index = (selected_index + 1) % choices.len();
+                    input_buffer.clear();
+                }
+                KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Err(Error::Cancelled);
+                }
+                KeyCode::Char(c) => {
+                    input_buffer.push(c);
+
+                    // If the buffer parses as a valid index, update selection.
+                    if let Ok(idx) = input_buffer.parse::<usize>() {
+                        if idx < choices.len() {
+                            selected_index = idx;
+                        }
+                    }
+                    // Otherwise, if the text starts any choice (case-insensitive), update selection.
+                    for (i, &choice) in choices.iter().enumerate() {
+                        if choice
+                            .to_lowercase()
+                            .starts_with(&input_buffer.to_lowercase())
+                        {
+                            selected_index = i;
+                            break;
                         }
-                        _ => {}
                     }
                 }
-            } // end inner input loop
-
-            // Disable raw mode.
-            disable_raw_mode().expect("Failed to disable raw mode");
-            println!();
-
-            // Determine the final selection.
-            let final_choice = if input_buffer.is_empty() {
-                choices[selected_index]
-            } else if let Ok(idx) = input_buffer.parse::<usize>() {
-                if idx < choices.len() {
-                    choices[idx]
-                } else {
-                    ""
+                KeyCode::Backspace => {
+                    input_buffer.pop();
                 }
+                _ => {}
+            }
+
+            // Update the prompt line.
+            queue!(out, MoveTo(2, prompt_row), Clear(ClearType::UntilNewLine))?;
+
+            if input_buffer.is_empty() {
+                // If nothing has been typed, display the full default/suggestion in normal style.
+                write!(out, "{}", choices[selected_index])?;
             } else {
-                let mut found = "";
-                for &choice in choices {
-                    if choice.to_lowercase() == input_buffer.to_lowercase() {
-                        found = choice;
-                        break;
-                    }
+                let suggestion = choices[selected_index];
+                // If the suggestion begins with the user's input (case-insensitive)
+                if suggestion
+                    .to_lowercase()
+                    .starts_with(&input_buffer.to_lowercase())
+                {
+                    let remainder = &suggestion[input_buffer.len()..];
+                    // Print the user's input as usual, then the remainder in the suggestion style.
+                    queue!(
+                        out,
+                        Print(&input_buffer),
+                        Print(painter.paint(&self.theme.prompt.suggestion, remainder))
+                    )?;
+                } else {
+                    write!(out, "{}", input_buffer)?;
                 }
-                found
-            };
+            }
+            out.flush()?;
+        }
 
-            // If the selection is invalid, render an error block and restart.
-            if final_choice.is_empty() {
-                self.error(format!(
-                    "Invalid selection: \"{}\". Please enter a valid index or choice.",
-                    input_buffer
-                ));
-                continue;
-            } else if selected_index > choices.len() {
-                self.error(format!(
-                    "Invalid selection: \"{}\". Please enter a valid index or choice.",
-                    input_buffer
-                ));
-                continue;
+        // Disable raw mode.
+        drop(raw_mode);
+        writeln!(out)?;
+
+        // Determine the final selection.
+        let final_choice = if input_buffer.is_empty() {
+            choices[selected_index]
+        } else if let Ok(idx) = input_buffer.parse::<usize>() {
+            if idx < choices.len() {
+                choices[idx]
             } else {
-                return final_choice.to_string();
+                ""
             }
-        }
+        } else {
+            let mut found = "";
+            for &choice in choices {
+                if choice.to_lowercase() == input_buffer.to_lowercase() {
+                    found = choice;
+                    break;
+                }
+            }
+            found
+        };
+
+        Ok((final_choice, input_buffer))
+    }
+}
+
+/// Keeps the terminal in raw mode for as long as the guard is alive.
+///
+/// Raw mode is disabled when the guard is dropped, so early returns on errors or cancellation
+/// never leave the terminal in raw mode.
+struct RawMode;
+
+impl RawMode {
+    /// Enables raw mode and returns a guard that disables it again on drop.
+    ///
+    /// # Returns
+    ///
+    /// The guard, or [`Error::TerminalUnavailable`] if raw mode cannot be enabled.
+    fn enable() -> Result<RawMode, Error> {
+        enable_raw_mode().map_err(Error::TerminalUnavailable)?;
+        Ok(RawMode)
+    }
+}
+
+/// Disables raw mode, ignoring any error since there is nothing left to recover.
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
     }
 }
 
@@ -1008,7 +1949,7 @@ impl RusticPrint {
 ///
 /// # Arguments
 ///
-/// * `stdout` - The mutable writer for stdout.
+/// * `out` - The writer to print to.
 /// * `wrap_width` - The width used to compute the padding.
 /// * `block_options` - Options that may contain style information.
 /// * `prefix` - The prefix to include at the beginning of the line.
@@ -1017,48 +1958,35 @@ impl RusticPrint {
 ///
 /// A `Result` indicating success or any encountered error.
 fn print_padding_line(
-    stdout: &mut impl Write,
+    out: &mut impl Write,
     wrap_width: usize,
     block_options: &BlockOptions,
     prefix: &str,
-) -> Result<(), Box<dyn Error>> {
-    let line = if wrap_width > prefix.len() {
-        format!("{}{}", prefix, " ".repeat(wrap_width - prefix.len()))
-    } else {
-        prefix.to_string()
-    };
+) -> io::Result<()> {
+    let line = format!("{}{}", prefix, padding(prefix, wrap_width));
 
     if let Some(style_cfg) = &block_options.style {
-        let mut styled_line = style(line);
-
-        if let Some(bg) = style_cfg.background {
-            styled_line = styled_line.on(bg);
-        }
-
-        if let Some(fg) = style_cfg.foreground {
-            styled_line = styled_line.with(fg);
-        }
-
         queue!(
-            stdout,
-            PrintStyledContent(styled_line),
+            out,
+            Print(Painted::new(Some(style_cfg.clone()), line)),
             ResetColor,
             Print("\r\n")
         )?;
         return Ok(());
     }
-    queue!(stdout, Print(line), Print("\r\n"))?;
+    queue!(out, Print(line), Print("\r\n"))?;
     Ok(())
 }
 
 /// Styles and prints a single line with appropriate end padding.
 ///
 /// The line is padded with spaces to ensure it spans the full `wrap_width`, and styling (background
-/// and foreground colors) is applied if specified in `block_options`.
+/// and foreground colors) is applied if specified in `block_options`. Escape sequences already in
+/// the line take up no width and are printed as-is.
 ///
 /// # Arguments
 ///
-/// * `stdout` - The mutable writer for stdout.
+/// * `out` - The writer to print to.
 /// * `line` - The text line to be styled and printed.
 /// * `wrap_width` - The total width for the line (used for padding).
 /// * `block_options` - Options that may contain styling information.
@@ -1067,21 +1995,24 @@ fn print_padding_line(
 ///
 /// A `Result` indicating success or any encountered error.
 fn styled_print_line(
-    stdout: &mut impl Write,
+    out: &mut impl Write,
     line: &str,
     wrap_width: usize,
     block_options: &BlockOptions,
-) -> Result<(), Box<dyn Error>> {
-    let end_padding = " ".repeat(wrap_width.saturating_sub(line.len()));
-    let mut styled = style(format!("{}{}", line, end_padding));
+) -> io::Result<()> {
+    let padded_line = format!("{}{}", line, padding(line, wrap_width));
     if let Some(style_cfg) = &block_options.style {
-        if let Some(bg) = style_cfg.background {
-            styled = styled.on(bg);
-        }
-        if let Some(fg) = style_cfg.foreground {
-            styled = styled.with(fg);
+        // Re-apply the block style wherever pre-styled text in the line resets it.
+        for piece in split_after_resets(&padded_line) {
+            queue!(out, Print(Painted::new(Some(style_cfg.clone()), piece)))?;
         }
+        queue!(out, ResetColor, Print("\n"))?;
+        return Ok(());
+    }
+    queue!(out, Print(&padded_line))?;
+    if padded_line.contains('\x1b') {
+        queue!(out, ResetColor)?;
     }
-    queue!(stdout, PrintStyledContent(styled), ResetColor, Print("\n"))?;
+    queue!(out, Print("\n"))?;
     Ok(())
 }
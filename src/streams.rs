@@ -0,0 +1,47 @@
+use std::io::{self, Write};
+use std::sync::MutexGuard;
+
+/// Controls which writer each kind of output is sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StreamPolicy {
+    /// Diagnostics (error, warning and caution blocks, plus interactive prompts) are sent to
+    /// the diagnostic writer, and all other content to the main writer.
+    #[default]
+    Split,
+    /// Everything is sent to the main writer.
+    Single,
+}
+
+/// The kind of output being rendered, used to pick a writer under a [`StreamPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Stream {
+    /// Regular content such as text, listings, tables and informational blocks.
+    Content,
+    /// Diagnostics and prompts, which should not end up in piped machine-readable output.
+    Diagnostic,
+}
+
+/// A locked handle to the writer that a piece of output was routed to.
+pub(crate) enum Output<'a, W: Write, E: Write> {
+    /// The main writer.
+    Main(MutexGuard<'a, W>),
+    /// The diagnostic writer.
+    Diagnostic(MutexGuard<'a, E>),
+}
+
+/// Forwards all writes to the routed writer.
+impl<W: Write, E: Write> Write for Output<'_, W, E> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Output::Main(writer) => writer.write(buf),
+            Output::Diagnostic(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Output::Main(writer) => writer.flush(),
+            Output::Diagnostic(writer) => writer.flush(),
+        }
+    }
+}
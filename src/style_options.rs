@@ -1,21 +1,214 @@
-use crossterm::style::Color;
+use crate::error::Error;
+use crossterm::style::{Attribute, Attributes, Color};
 
 #[derive(Debug, Clone)]
 pub struct StyleOptions {
     pub foreground: Option<Color>,
     pub background: Option<Color>,
+    pub attributes: Attributes,
 }
 
-/// Returns a new `StyleOptions` instance with no foreground or background colors set.
+impl StyleOptions {
+    /// Adds a text attribute, such as `Attribute::Bold` or `Attribute::Dim`, to the style.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute` - The attribute to add.
+    ///
+    /// # Returns
+    ///
+    /// The `StyleOptions` with the attribute set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crossterm::style::{Attribute, Color};
+    /// use rustic_print::style_options::StyleOptions;
+    ///
+    /// let style = StyleOptions {
+    ///     foreground: Some(Color::Red),
+    ///     ..Default::default()
+    /// }
+    /// .with_attribute(Attribute::Bold)
+    /// .with_attribute(Attribute::Underlined);
+    ///
+    /// assert!(style.attributes.has(Attribute::Bold));
+    /// assert!(!style.attributes.has(Attribute::Italic));
+    /// ```
+    pub fn with_attribute(mut self, attribute: Attribute) -> StyleOptions {
+        self.attributes.set(attribute);
+        self
+    }
+
+    /// Layers another style on top of this one.
+    ///
+    /// Colors set by `over` replace this style's colors, colors it leaves unset are kept, and the
+    /// text attributes of both styles are combined.
+    ///
+    /// # Arguments
+    ///
+    /// * `over` - The style that takes precedence.
+    ///
+    /// # Returns
+    ///
+    /// The combined `StyleOptions`.
+    pub(crate) fn layer(&self, over: &StyleOptions) -> StyleOptions {
+        StyleOptions {
+            foreground: over.foreground.or(self.foreground),
+            background: over.background.or(self.background),
+            attributes: self.attributes | over.attributes,
+        }
+    }
+}
+
+/// Returns a new `StyleOptions` instance with no colors or text attributes set.
 ///
 /// # Returns
 ///
-/// A `StyleOptions` instance with both `foreground` and `background` set to `None`.
+/// A `StyleOptions` instance with both `foreground` and `background` set to `None` and no
+/// `attributes`.
 impl Default for StyleOptions {
     fn default() -> Self {
         StyleOptions {
             foreground: None,
             background: None,
+            attributes: Attributes::default(),
+        }
+    }
+}
+
+/// Parses a list of text attributes separated by commas, spaces or `+`.
+///
+/// Recognized names are `bold`, `italic`, `underline`, `dim`, `blink`, `reverse`,
+/// `strikethrough` and `hidden`, case-insensitively.
+///
+/// # Arguments
+///
+/// * `value` - The attributes to parse, e.g. `"bold, underline"`.
+///
+/// # Returns
+///
+/// The parsed `Attributes`, or `Error::InvalidInput` if a name is not a recognized attribute.
+///
+/// # Example
+///
+/// ```rust
+/// use crossterm::style::Attribute;
+/// use rustic_print::style_options::parse_attributes;
+///
+/// let attributes = parse_attributes("bold+dim").unwrap();
+/// assert!(attributes.has(Attribute::Bold) && attributes.has(Attribute::Dim));
+/// assert!(parse_attributes("sparkly").is_err());
+/// ```
+pub fn parse_attributes(value: &str) -> Result<Attributes, Error> {
+    let mut attributes = Attributes::default();
+    for name in value
+        .split(|c: char| c == ',' || c == '+' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+    {
+        attributes.set(parse_attribute(name)?);
+    }
+    Ok(attributes)
+}
+
+/// Parses a single text attribute name.
+///
+/// # Arguments
+///
+/// * `name` - The attribute name, e.g. `"bold"`.
+///
+/// # Returns
+///
+/// The parsed `Attribute`, or `Error::InvalidInput` if the name is not recognized.
+fn parse_attribute(name: &str) -> Result<Attribute, Error> {
+    match name.to_ascii_lowercase().replace(['_', '-'], "").as_str() {
+        "bold" => Ok(Attribute::Bold),
+        "italic" => Ok(Attribute::Italic),
+        "underline" | "underlined" => Ok(Attribute::Underlined),
+        "dim" => Ok(Attribute::Dim),
+        "blink" => Ok(Attribute::SlowBlink),
+        "reverse" => Ok(Attribute::Reverse),
+        "strikethrough" => Ok(Attribute::CrossedOut),
+        "hidden" => Ok(Attribute::Hidden),
+        _ => Err(Error::InvalidInput(format!(
+            "unrecognized text attribute: {:?}",
+            name
+        ))),
+    }
+}
+
+/// Parses a color from a name, a `#rrggbb` hex code, or an ANSI 256-color index.
+///
+/// Names are case-insensitive and may separate words with `_`, `-` or spaces, so `dark_red`,
+/// `dark-red` and `Dark Red` are all accepted. Both `grey` and `gray` spellings are supported.
+///
+/// # Arguments
+///
+/// * `value` - The color to parse, e.g. `"red"`, `"#ff8800"` or `"208"`.
+///
+/// # Returns
+///
+/// The parsed `Color`, or `Error::InvalidInput` if the value is not a recognized color.
+///
+/// # Example
+///
+/// ```rust
+/// use crossterm::style::Color;
+/// use rustic_print::style_options::parse_color;
+///
+/// assert_eq!(parse_color("dark_red").unwrap(), Color::DarkRed);
+/// assert_eq!(parse_color("#268bd2").unwrap(), Color::Rgb { r: 38, g: 139, b: 210 });
+/// assert_eq!(parse_color("208").unwrap(), Color::AnsiValue(208));
+/// assert!(parse_color("not-a-color").is_err());
+/// ```
+pub fn parse_color(value: &str) -> Result<Color, Error> {
+    let value = value.trim();
+    let invalid = || Error::InvalidInput(format!("unrecognized color: {:?}", value));
+
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return Err(invalid());
         }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+        return Ok(Color::Rgb {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        });
+    }
+
+    if value.chars().all(|c| c.is_ascii_digit()) && !value.is_empty() {
+        return value
+            .parse::<u8>()
+            .map(Color::AnsiValue)
+            .map_err(|_| invalid());
+    }
+
+    let name: String = value
+        .chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .collect::<String>()
+        .to_ascii_lowercase()
+        .replace("gray", "grey");
+
+    match name.as_str() {
+        "reset" => Ok(Color::Reset),
+        "black" => Ok(Color::Black),
+        "darkgrey" => Ok(Color::DarkGrey),
+        "red" => Ok(Color::Red),
+        "darkred" => Ok(Color::DarkRed),
+        "green" => Ok(Color::Green),
+        "darkgreen" => Ok(Color::DarkGreen),
+        "yellow" => Ok(Color::Yellow),
+        "darkyellow" => Ok(Color::DarkYellow),
+        "blue" => Ok(Color::Blue),
+        "darkblue" => Ok(Color::DarkBlue),
+        "magenta" => Ok(Color::Magenta),
+        "darkmagenta" => Ok(Color::DarkMagenta),
+        "cyan" => Ok(Color::Cyan),
+        "darkcyan" => Ok(Color::DarkCyan),
+        "white" => Ok(Color::White),
+        "grey" => Ok(Color::Grey),
+        _ => Err(invalid()),
     }
 }
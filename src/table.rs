@@ -1,42 +1,739 @@
-use crossterm::style::Stylize;
+use crate::color::{ColorChoice, Painter};
+use crate::error::Error;
+use crate::style_options::StyleOptions;
+use crate::width::{display_width, split_after_resets, strip_ansi};
+use crossterm::style::{Attribute, Color};
+use crossterm::terminal;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{self, stdout, IsTerminal, Write};
 
-pub struct Table<'a> {
-    headers: Vec<&'a str>,
-    rows: Vec<Vec<&'a str>>,
-    column_widths: Vec<usize>,
+mod aggregate;
+mod arrange;
+mod builder;
+mod expanded;
+mod export;
+mod footer;
+mod layout;
+#[cfg(feature = "serde")]
+mod serialize;
+mod stream;
+mod style;
+mod tabled;
+
+pub use self::aggregate::Aggregate;
+pub use self::arrange::SortOrder;
+pub use self::builder::TableBuilder;
+pub use self::expanded::Expanded;
+use self::layout::{cell_width, Layout};
+pub use self::stream::StreamOptions;
+pub use self::style::{BorderLine, TableStyle};
+pub use self::tabled::Tabled;
+#[cfg(feature = "derive")]
+pub use rustic_print_derive::Tabled;
+
+/// How the contents of a column are positioned within its width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+    /// Cells start at the left edge of the column.
+    #[default]
+    Left,
+    /// Cells end at the right edge of the column.
+    Right,
+    /// Cells are centered, with any odd space on the right.
+    Center,
+    /// Right-aligned if every non-empty cell in the column is a number, otherwise left-aligned.
+    ///
+    /// Numbers may have a sign, a decimal point, `,` or `_` digit separators and a trailing `%`.
+    Auto,
+}
+
+/// Where the lines of a cell are placed when its row is taller than the cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlignment {
+    /// Lines start at the top of the row.
+    #[default]
+    Top,
+    /// Lines are centered, with any odd line below them.
+    Middle,
+    /// Lines end at the bottom of the row.
+    Bottom,
+}
+
+/// What happens to cell contents that are wider than their column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// The contents are wrapped onto as many lines as needed, making the row taller.
+    #[default]
+    Wrap,
+    /// The contents are cut short and end with `…`.
+    Truncate,
+}
+
+/// What to do with rows that have a different number of cells than the table has headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RaggedRows {
+    /// Short rows are padded with empty cells, and long rows add columns with empty headers, so
+    /// no cell is lost.
+    #[default]
+    ExtendHeaders,
+    /// Short rows are padded with empty cells, and cells beyond the last header are dropped.
+    Pad,
+    /// Any row whose length differs from the header's is an error.
+    Error,
+}
+
+/// Settings for a single table column.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnOptions {
+    /// How the header and cells are aligned, or `None` to use the table's alignment.
+    pub alignment: Option<Alignment>,
+    /// How cells are placed in rows taller than them, or `None` to use the table's vertical
+    /// alignment.
+    pub vertical_alignment: Option<VerticalAlignment>,
+    /// The narrowest the column may be, even if its contents are narrower.
+    pub min_width: Option<usize>,
+    /// The widest the column may be; longer contents overflow.
+    pub max_width: Option<usize>,
+    /// What to do with contents wider than the column, or `None` to use the table's overflow.
+    pub overflow: Option<Overflow>,
+    /// The style of this column's header, overriding the table's header style.
+    pub header_style: Option<StyleOptions>,
+    /// The style of this column's cells, layered over the table's body style.
+    pub style: Option<StyleOptions>,
+}
+
+/// A table of headers and rows that owns its contents.
+///
+/// Build one with [`Table::new`] from existing strings, or with [`Table::builder`] to add rows
+/// incrementally and configure individual columns.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    columns: Vec<ColumnOptions>,
+    header_style: Option<StyleOptions>,
+    body_style: Option<StyleOptions>,
+    zebra_style: Option<StyleOptions>,
+    row_styles: HashMap<usize, StyleOptions>,
+    cell_styles: HashMap<(usize, usize), StyleOptions>,
+    row_spans: HashMap<(usize, usize), usize>,
+    footer: Vec<String>,
+    footer_spans: HashMap<usize, usize>,
+    aggregates: HashMap<usize, Aggregate>,
+    expanded: Expanded,
+    group_column: Option<usize>,
+    subtotal_columns: Vec<usize>,
+    alignment: Alignment,
+    vertical_alignment: VerticalAlignment,
+    overflow: Overflow,
+    max_width: Option<usize>,
+    style: TableStyle,
+    color_choice: ColorChoice,
 }
 
-impl<'a> Table<'a> {
+impl Table {
     /// Creates a new `Table` instance with the given headers and rows.
     ///
-    /// It calculates the column widths based on the maximum length of header and cell contents.
+    /// Column widths are calculated when the table is printed, based on the maximum display width
+    /// of header and cell contents, so wide characters and emoji keep the columns aligned.
     ///
     /// # Arguments
     ///
-    /// * `headers` - A vector of string slices representing the table headers.
-    /// * `rows` - A vector of rows, where each row is a vector of string slices.
+    /// * `headers` - The table headers.
+    /// * `rows` - A vector of rows, where each row is a vector of cells.
     ///
     /// # Returns
     ///
     /// A new instance of `Table`.
-    pub fn new(headers: Vec<&'a str>, rows: Vec<Vec<&'a str>>) -> Table<'a> {
-        // Initialize each column width to the header length.
-        let mut column_widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
-
-        // Update widths based on row cell lengths.
-        for row in &rows {
-            for (i, cell) in row.iter().enumerate() {
-                if cell.len() > column_widths[i] {
-                    column_widths[i] = cell.len();
-                }
+    pub fn new<H, C>(headers: Vec<H>, rows: Vec<Vec<C>>) -> Table
+    where
+        H: Into<String>,
+        C: Into<String>,
+    {
+        Table {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(Into::into).collect())
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new `Table` instance, checking that every row has one cell per header.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The table headers.
+    /// * `rows` - A vector of rows, where each row is a vector of cells.
+    ///
+    /// # Returns
+    ///
+    /// The `Table`, or `Error::InvalidInput` naming the first row with too few or too many cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any row's length differs from the number of headers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    ///
+    /// let error = Table::try_new(
+    ///     vec!["Host", "Port"],
+    ///     vec![vec!["db", "5432"], vec!["cache", "6379", "primary"]],
+    /// )
+    /// .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "invalid input: row 2 has 3 cells, but the table has 2 columns: [\"cache\", \"6379\", \"primary\"]"
+    /// );
+    /// ```
+    pub fn try_new<H, C>(headers: Vec<H>, rows: Vec<Vec<C>>) -> Result<Table, Error>
+    where
+        H: Into<String>,
+        C: Into<String>,
+    {
+        Table::new(headers, rows).conform_rows(RaggedRows::Error)
+    }
+
+    /// Creates a new `Table` without a header row.
+    ///
+    /// The table has as many columns as its longest row, and shorter rows are padded with empty
+    /// cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - A vector of rows, where each row is a vector of cells.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `Table` without headers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    ///
+    /// let table = Table::headerless(vec![vec!["region", "eu-west-1"], vec!["zones", "3"]]);
+    ///
+    /// let rendered = table.render();
+    /// let lines: Vec<&str> = rendered.lines().map(str::trim_end).collect();
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         "-------- -----------",
+    ///         " region   eu-west-1",
+    ///         " zones    3",
+    ///         "-------- -----------",
+    ///     ]
+    /// );
+    /// ```
+    pub fn headerless<C: Into<String>>(rows: Vec<Vec<C>>) -> Table {
+        Table::new(Vec::<String>::new(), rows)
+    }
+
+    /// Makes every row as long as the header, following the given policy.
+    ///
+    /// Tables without headers have as many columns as their longest row, so their rows are only
+    /// ever padded.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - What to do with rows that are too short or too long.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with rows of equal length, or `Error::InvalidInput` naming the first row that
+    /// does not fit if the policy is `RaggedRows::Error`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the policy is `RaggedRows::Error` and a row's length differs from the
+    /// number of headers.
+    pub fn conform_rows(mut self, policy: RaggedRows) -> Result<Table, Error> {
+        let columns = if self.headers.is_empty() {
+            self.column_count()
+        } else {
+            self.headers.len()
+        };
+
+        if policy == RaggedRows::Error && !self.headers.is_empty() {
+            if let Some((i, row)) = self
+                .rows
+                .iter()
+                .enumerate()
+                .find(|(_, row)| row.len() != columns)
+            {
+                return Err(Error::InvalidInput(format!(
+                    "row {} has {} cells, but the table has {} columns: {:?}",
+                    i + 1,
+                    row.len(),
+                    columns,
+                    row
+                )));
             }
         }
 
-        Table {
-            headers,
-            rows,
-            column_widths,
+        let columns = match policy {
+            RaggedRows::ExtendHeaders if !self.headers.is_empty() => {
+                let columns = self.column_count();
+                self.headers.resize(columns, String::new());
+                columns
+            }
+            _ => columns,
+        };
+        for row in &mut self.rows {
+            row.resize(columns, String::new());
+        }
+        Ok(self)
+    }
+
+    /// Creates a builder for a table, for adding rows one at a time and configuring columns.
+    ///
+    /// # Returns
+    ///
+    /// An empty `TableBuilder`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{Alignment, ColumnOptions, Table};
+    ///
+    /// let mut builder = Table::builder().header(["Package", "Downloads"]).column(
+    ///     1,
+    ///     ColumnOptions {
+    ///         alignment: Some(Alignment::Right),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// for (name, downloads) in [("serde", 512_000), ("rand", 64_000)] {
+    ///     builder = builder.row([name.to_string(), downloads.to_string()]);
+    /// }
+    ///
+    /// let rendered = builder.build().render();
+    /// assert!(rendered.contains(" serde        512000 "));
+    /// ```
+    pub fn builder() -> TableBuilder {
+        TableBuilder::new()
+    }
+
+    /// Appends a row to the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The cells of the row; anything that implements `Display`.
+    pub fn add_row<I, T>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        self.rows
+            .push(row.into_iter().map(|cell| cell.to_string()).collect());
+    }
+
+    /// Sets the style used for the header row.
+    ///
+    /// Headers default to bold green, and a column's own `header_style` takes precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `header_style` - The colors and text attributes to apply to each header.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new header style.
+    pub fn with_header_style(mut self, header_style: StyleOptions) -> Table {
+        self.header_style = Some(header_style);
+        self
+    }
+
+    /// Sets the style of every cell outside the header.
+    ///
+    /// This is the lowest layer of cell styling. Column, row and cell styles are layered over it,
+    /// in that order: each layer's colors replace those below it, and text attributes combine.
+    ///
+    /// # Arguments
+    ///
+    /// * `body_style` - The colors and text attributes to apply to every cell.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new body style.
+    pub fn with_body_style(mut self, body_style: StyleOptions) -> Table {
+        self.body_style = Some(body_style);
+        self
+    }
+
+    /// Stripes the table by styling every other row, starting with the second.
+    ///
+    /// The stripe is layered like a row style, below any style set with `with_row_style`, so a
+    /// background color fills alternating rows while highlighted rows and cells keep their own
+    /// colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `zebra_style` - The style of the striped rows, usually just a background color.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with striped rows.
+    pub fn with_zebra(mut self, zebra_style: StyleOptions) -> Table {
+        self.zebra_style = Some(zebra_style);
+        self
+    }
+
+    /// Sets the style of a whole row, such as dimming a disabled entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero-based index of the row, not counting the header.
+    /// * `style` - The colors and text attributes to apply to the row's cells.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new row style.
+    pub fn with_row_style(mut self, row: usize, style: StyleOptions) -> Table {
+        self.row_styles.insert(row, style);
+        self
+    }
+
+    /// Sets the style of a single cell, such as a red `FAILED` status.
+    ///
+    /// Cell styles take precedence over row, column and body styles.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero-based index of the row, not counting the header.
+    /// * `column` - The zero-based index of the column.
+    /// * `style` - The colors and text attributes to apply to the cell.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new cell style.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crossterm::style::{Attribute, Color};
+    /// use rustic_print::color::ColorChoice;
+    /// use rustic_print::style_options::StyleOptions;
+    /// use rustic_print::table::Table;
+    ///
+    /// let failed = StyleOptions {
+    ///     foreground: Some(Color::Red),
+    ///     ..Default::default()
+    /// }
+    /// .with_attribute(Attribute::Bold);
+    /// let table = Table::new(
+    ///     vec!["Job", "Status"],
+    ///     vec![vec!["lint", "passed"], vec!["test", "FAILED"], vec!["docs", "skipped"]],
+    /// )
+    /// .with_cell_style(1, 1, failed)
+    /// .with_row_style(2, StyleOptions::default().with_attribute(Attribute::Dim))
+    /// .with_color_choice(ColorChoice::Always);
+    ///
+    /// let rendered = table.render();
+    /// assert!(rendered.contains("\x1b[1m FAILED "));
+    /// assert!(rendered.contains("\x1b[2m docs "));
+    /// ```
+    pub fn with_cell_style(mut self, row: usize, column: usize, style: StyleOptions) -> Table {
+        self.cell_styles.insert((row, column), style);
+        self
+    }
+
+    /// Makes a cell span several columns, covering the cells to its right.
+    ///
+    /// A spanning cell takes the alignment and overflow of its first column. The columns it spans
+    /// are widened, if needed, so that it fits, within their maximum widths and the width of the
+    /// table. The cells it covers are not printed; use [`Table::try_with_span`] to make sure none
+    /// of them has contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero-based index of the row, not counting the header.
+    /// * `column` - The zero-based index of the first column the cell spans.
+    /// * `columns` - The number of columns the cell spans.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new span.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    ///
+    /// let table = Table::new(vec!["a", "b"], vec![vec!["wide cell spanning"], vec!["1", "2"]])
+    ///     .with_span(0, 0, 2);
+    ///
+    /// let rendered = table.render();
+    /// let lines: Vec<&str> = rendered.lines().map(str::trim_end).collect();
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         "---------- ---------",
+    ///         " a          b",
+    ///         "---------- ---------",
+    ///         " wide cell spanning",
+    ///         " 1          2",
+    ///         "---------- ---------",
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_span(mut self, row: usize, column: usize, columns: usize) -> Table {
+        self.row_spans.insert((row, column), columns);
+        self
+    }
+
+    /// Makes a cell span several columns, checking that the cells it covers are empty.
+    ///
+    /// See [`Table::with_span`] for how spanning cells are printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero-based index of the row, not counting the header.
+    /// * `column` - The zero-based index of the first column the cell spans.
+    /// * `columns` - The number of columns the cell spans.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new span, or `Error::InvalidInput` naming the first covered cell
+    /// that has contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any cell the span covers is not empty, since it would not be printed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    ///
+    /// let table = Table::new(vec!["a", "b"], vec![vec!["wide cell spanning", "x"]]);
+    ///
+    /// let error = table.try_with_span(0, 0, 2).unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "invalid input: the span at row 1, column 1 covers the non-empty cell in column 2: \"x\""
+    /// );
+    /// ```
+    pub fn try_with_span(self, row: usize, column: usize, columns: usize) -> Result<Table, Error> {
+        let covered = self
+            .rows
+            .get(row)
+            .into_iter()
+            .flat_map(|cells| cells.iter().enumerate())
+            .skip(column + 1)
+            .take(columns.saturating_sub(1))
+            .find(|(_, cell)| !cell.is_empty());
+        if let Some((covered, cell)) = covered {
+            return Err(Error::InvalidInput(format!(
+                "the span at row {}, column {} covers the non-empty cell in column {}: {:?}",
+                row + 1,
+                column + 1,
+                covered + 1,
+                cell
+            )));
         }
+        Ok(self.with_span(row, column, columns))
+    }
+
+    /// Sets the alignment of every column that does not set its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment` - The default alignment, `Alignment::Left` unless changed.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new default alignment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{Alignment, Table};
+    ///
+    /// let table = Table::new(
+    ///     vec!["Task", "Duration (ms)"],
+    ///     vec![vec!["build", "1,250"], vec!["test", "98.5"]],
+    /// )
+    /// .with_alignment(Alignment::Auto);
+    ///
+    /// assert!(table.render().contains(" test             98.5 "));
+    /// ```
+    pub fn with_alignment(mut self, alignment: Alignment) -> Table {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets where cells are placed in rows taller than them, for columns that do not set their own
+    /// vertical alignment.
+    ///
+    /// Cells can span several lines, either by containing `\n` or by wrapping, and each row is as
+    /// tall as its tallest cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertical_alignment` - The default vertical alignment, `VerticalAlignment::Top` unless
+    ///   changed.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new default vertical alignment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{Table, VerticalAlignment};
+    ///
+    /// let table = Table::new(
+    ///     vec!["Service", "Address"],
+    ///     vec![vec!["billing", "221B Baker Street\nLondon\nNW1 6XE"]],
+    /// )
+    /// .with_vertical_alignment(VerticalAlignment::Middle);
+    ///
+    /// let rendered = table.render();
+    /// let lines: Vec<&str> = rendered.lines().map(str::trim_end).collect();
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         "--------- -------------------",
+    ///         " Service   Address",
+    ///         "--------- -------------------",
+    ///         "           221B Baker Street",
+    ///         " billing   London",
+    ///         "           NW1 6XE",
+    ///         "--------- -------------------",
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Table {
+        self.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Sets what happens to cells that are wider than their column, for columns that do not set
+    /// their own overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `overflow` - Whether long cells wrap (the default) or are truncated.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new overflow.
+    pub fn with_overflow(mut self, overflow: Overflow) -> Table {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the total width the table must fit in, including its borders.
+    ///
+    /// By default, tables fit the width of the terminal, if there is one. When a table is too
+    /// wide, its widest columns are shrunk first, and their cells wrap or are truncated.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_width` - The maximum width of the table, in columns of the terminal.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new maximum width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{Overflow, Table};
+    ///
+    /// let table = Table::new(
+    ///     vec!["Step", "Log"],
+    ///     vec![vec!["fetch", "downloaded 12 crates from the registry"]],
+    /// )
+    /// .with_max_width(30);
+    ///
+    /// let rendered = table.render();
+    /// let lines: Vec<&str> = rendered.lines().map(str::trim_end).collect();
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         "------- ----------------------",
+    ///         " Step    Log",
+    ///         "------- ----------------------",
+    ///         " fetch   downloaded 12 crates",
+    ///         "         from the registry",
+    ///         "------- ----------------------",
+    ///     ]
+    /// );
+    ///
+    /// let truncated = table.with_overflow(Overflow::Truncate).render();
+    /// assert!(truncated.contains(" fetch   downloaded 12 crate… "));
+    /// ```
+    pub fn with_max_width(mut self, max_width: usize) -> Table {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the borders and separators the table is drawn with.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The table style, such as `TableStyle::unicode()` or `TableStyle::markdown()`.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new style.
+    pub fn with_style(mut self, style: TableStyle) -> Table {
+        self.style = style;
+        self
+    }
+
+    /// Sets whether the table is styled with colors and text attributes.
+    ///
+    /// With the default `ColorChoice::Auto`, `print_table` styles the table only if stdout is a
+    /// terminal, while `write_table` and `render` leave it plain.
+    ///
+    /// # Arguments
+    ///
+    /// * `color_choice` - The color choice to use.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new color choice.
+    pub fn with_color_choice(mut self, color_choice: ColorChoice) -> Table {
+        self.color_choice = color_choice;
+        self
+    }
+
+    /// Sets the options of a single column.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based index of the column.
+    /// * `options` - The settings for that column.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new column options.
+    pub fn with_column(mut self, index: usize, options: ColumnOptions) -> Table {
+        if self.columns.len() <= index {
+            self.columns.resize_with(index + 1, ColumnOptions::default);
+        }
+        self.columns[index] = options;
+        self
+    }
+
+    /// Returns the headers of the table.
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// Returns the rows of the table.
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
     }
 
     /// Creates a horizontal line based on the widths of each column.
@@ -49,53 +746,629 @@ impl<'a> Table<'a> {
     ///
     /// A `String` representing the horizontal line.
     pub fn create_line(&self, filler: char) -> String {
-        self.column_widths
-            .iter()
-            .map(|&len| filler.to_string().repeat(len + 2))
-            .collect::<Vec<_>>()
-            .join(" ")
+        self.layout(stdout().is_terminal())
+            .line(&BorderLine::new("", filler, " ", ""))
     }
 
     /// Prints the table to stdout with styled headers and borders.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to stdout fails.
     pub fn print_table(&self) {
-        let border_line = self.create_line('-');
-        println!("{}", border_line);
+        let is_terminal = stdout().is_terminal();
+        let painter = self.color_choice.painter(is_terminal);
+        self.write_styled(
+            &mut stdout(),
+            &painter,
+            &default_header_style(),
+            is_terminal,
+        )
+        .expect("Failed to print table");
+    }
 
-        // Print headers in green and bold.
-        let header_str = self
-            .headers
-            .iter()
-            .enumerate()
-            .map(|(i, header)| {
-                format!(
-                    " {}{} ",
-                    header.green().bold(),
-                    " ".repeat(self.column_widths[i].saturating_sub(header.len()))
-                )
+    /// Renders the table into a `String` with borders and, if enabled, styled headers.
+    ///
+    /// Like `write_table`, the output is only styled with `ColorChoice::Always`, and only
+    /// limited in width by `with_max_width`.
+    ///
+    /// # Returns
+    ///
+    /// The rendered table.
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        self.write_table(&mut buffer)
+            .expect("Writing to a Vec<u8> cannot fail");
+        String::from_utf8_lossy(&buffer).into_owned()
+    }
+
+    /// Writes the table to the given writer with borders and, if enabled, styled headers.
+    ///
+    /// The writer is treated as not being a terminal, so the headers are only styled with
+    /// `ColorChoice::Always`, and the table is only limited in width by `with_max_width`, not by
+    /// the width of the terminal the program runs in.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the table to.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    pub fn write_table(&self, out: &mut impl Write) -> io::Result<()> {
+        self.write_styled(
+            out,
+            &self.color_choice.painter(false),
+            &default_header_style(),
+            false,
+        )
+    }
+
+    /// Writes the table to the given writer, styling it with the given painter.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the table to.
+    /// * `painter` - Decides whether and how the header is styled.
+    /// * `fallback_header_style` - The header style to use if the table does not set one.
+    /// * `is_terminal` - Whether the output is a terminal, whose width the table then fits unless
+    ///   it sets a maximum width.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    pub(crate) fn write_styled(
+        &self,
+        out: &mut impl Write,
+        painter: &Painter,
+        fallback_header_style: &StyleOptions,
+        is_terminal: bool,
+    ) -> io::Result<()> {
+        if self.is_expanded(is_terminal) {
+            return self.write_expanded(out, painter, fallback_header_style, is_terminal);
+        }
+
+        let layout = self.layout(is_terminal);
+        self.write_header(out, &layout, painter, fallback_header_style)?;
+        self.write_body(out, &layout, painter, fallback_header_style)?;
+        self.write_trailer(out, &layout, painter)?;
+        out.flush()
+    }
+
+    /// Renders the table in three sections: the header, the body and the trailer.
+    ///
+    /// Concatenated, the sections are the same as the output of `write_styled`. The expanded
+    /// layout is all body.
+    ///
+    /// # Arguments
+    ///
+    /// * `painter` - Decides whether and how the table is styled.
+    /// * `fallback_header_style` - The header style to use if the table does not set one.
+    /// * `is_terminal` - Whether the output is a terminal, whose width the table then fits unless
+    ///   it sets a maximum width.
+    ///
+    /// # Returns
+    ///
+    /// The top border, header row and line under it; the rows, groups and subtotals; and the
+    /// footer and bottom border.
+    pub(crate) fn render_sections(
+        &self,
+        painter: &Painter,
+        fallback_header_style: &StyleOptions,
+        is_terminal: bool,
+    ) -> [String; 3] {
+        let mut sections: [Vec<u8>; 3] = Default::default();
+        let written = if self.is_expanded(is_terminal) {
+            self.write_expanded(
+                &mut sections[1],
+                painter,
+                fallback_header_style,
+                is_terminal,
+            )
+        } else {
+            let layout = self.layout(is_terminal);
+            let [header, body, trailer] = &mut sections;
+            self.write_header(header, &layout, painter, fallback_header_style)
+                .and_then(|()| self.write_body(body, &layout, painter, fallback_header_style))
+                .and_then(|()| self.write_trailer(trailer, &layout, painter))
+        };
+        written.expect("Writing to a Vec<u8> cannot fail");
+        sections.map(|section| String::from_utf8_lossy(&section).into_owned())
+    }
+
+    /// Writes the rows, or the groups of rows with their titles and subtotals.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the rows to.
+    /// * `layout` - The layout the table is printed with.
+    /// * `painter` - Decides whether and how the rows are styled.
+    /// * `fallback_header_style` - The group title style to use if the table does not set a
+    ///   header style.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    fn write_body(
+        &self,
+        out: &mut impl Write,
+        layout: &Layout,
+        painter: &Painter,
+        fallback_header_style: &StyleOptions,
+    ) -> io::Result<()> {
+        let table_header_style = self.header_style.as_ref().unwrap_or(fallback_header_style);
+        match self.group_column {
+            None => {
+                let rows: Vec<usize> = (0..self.rows.len()).collect();
+                self.write_rows(out, layout, painter, &rows)?;
+            }
+            Some(column) => {
+                // Print each group under a line naming it, with groups set apart like the header.
+                for (i, (value, rows)) in self.groups(column).into_iter().enumerate() {
+                    if i > 0 {
+                        layout.write_line(out, self.style.header_separator.as_ref())?;
+                    }
+                    let title = match self.headers.get(column).filter(|h| !h.is_empty()) {
+                        Some(header) => format!("{}: {}", header, value),
+                        None => value,
+                    };
+                    layout.write_span(out, &title, |line| {
+                        paint_cell(painter, table_header_style, line)
+                    })?;
+                    self.write_rows(out, layout, painter, &rows)?;
+                    if !self.subtotal_columns.is_empty() {
+                        layout.write_line(out, self.style.row_separator.as_ref())?;
+                        layout.write_row(out, &self.subtotal_row(&rows), |_, cell| {
+                            paint_cell(painter, &summary_style(), cell)
+                        })?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the footer, if the table has one, and the bottom border.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the footer to.
+    /// * `layout` - The layout the table is printed with.
+    /// * `painter` - Decides whether and how the footer is styled.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    fn write_trailer(
+        &self,
+        out: &mut impl Write,
+        layout: &Layout,
+        painter: &Painter,
+    ) -> io::Result<()> {
+        // Print the footer, set apart from the rows like the header.
+        if let Some(footer) = self.footer_row() {
+            layout.write_line(out, self.style.header_separator.as_ref())?;
+            layout.write_spanned_row(
+                out,
+                &footer,
+                |column| self.footer_spans.get(&column).copied().unwrap_or(1),
+                |_, cell| paint_cell(painter, &summary_style(), cell),
+            )?;
+        }
+
+        layout.write_line(out, self.style.bottom.as_ref())
+    }
+
+    /// Writes the top border, the header row and the line under it.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the header to.
+    /// * `layout` - The layout the table is printed with.
+    /// * `painter` - Decides whether and how the header is styled.
+    /// * `fallback_header_style` - The header style to use if the table does not set one.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    fn write_header(
+        &self,
+        out: &mut impl Write,
+        layout: &Layout,
+        painter: &Painter,
+        fallback_header_style: &StyleOptions,
+    ) -> io::Result<()> {
+        layout.write_line(out, self.style.top.as_ref())?;
+        self.write_header_row(out, layout, painter, fallback_header_style)
+    }
+
+    /// Writes the header row and the line under it, if the table has headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the header to.
+    /// * `layout` - The layout the table is printed with.
+    /// * `painter` - Decides whether and how the header is styled.
+    /// * `fallback_header_style` - The header style to use if the table does not set one.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    fn write_header_row(
+        &self,
+        out: &mut impl Write,
+        layout: &Layout,
+        painter: &Painter,
+        fallback_header_style: &StyleOptions,
+    ) -> io::Result<()> {
+        if self.headers.is_empty() {
+            return Ok(());
+        }
+
+        // Print headers using the column's header style, falling back to the table's.
+        let table_header_style = self.header_style.as_ref().unwrap_or(fallback_header_style);
+        let header_styles: Vec<StyleOptions> = (0..layout.widths.len())
+            .map(|i| {
+                self.column_options(i)
+                    .header_style
+                    .unwrap_or_else(|| table_header_style.clone())
             })
-            .collect::<Vec<_>>()
-            .join(" ");
-        println!("{}", header_str);
+            .collect();
+        layout.write_row(out, &self.headers, |i, cell| {
+            paint_cell(painter, &header_styles[i], cell)
+        })?;
+        layout.write_line(out, self.style.header_separator.as_ref())
+    }
+
+    /// Writes the given rows, separated by the row separator if the style has one.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the rows to.
+    /// * `layout` - The layout the table is printed with.
+    /// * `painter` - Decides whether and how the cells are styled.
+    /// * `rows` - The indexes of the rows to write.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    fn write_rows(
+        &self,
+        out: &mut impl Write,
+        layout: &Layout,
+        painter: &Painter,
+        rows: &[usize],
+    ) -> io::Result<()> {
+        for (position, &i) in rows.iter().enumerate() {
+            if position > 0 {
+                layout.write_line(out, self.style.row_separator.as_ref())?;
+            }
+            layout.write_spanned_row(
+                out,
+                &self.rows[i],
+                |column| self.row_spans.get(&(i, column)).copied().unwrap_or(1),
+                |column, cell| paint_cell(painter, &self.cell_style(i, column), cell),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Resolves the style of a cell outside the header.
+    ///
+    /// The body style, column style, zebra stripe, row style and cell style are layered in that
+    /// order, so the most specific style wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero-based index of the row.
+    /// * `column` - The zero-based index of the column.
+    ///
+    /// # Returns
+    ///
+    /// The combined `StyleOptions`, empty if no style applies.
+    fn cell_style(&self, row: usize, column: usize) -> StyleOptions {
+        let column_style = self.columns.get(column).and_then(|c| c.style.as_ref());
+        let zebra_style = self.zebra_style.as_ref().filter(|_| row % 2 == 1);
+        [
+            self.body_style.as_ref(),
+            column_style,
+            zebra_style,
+            self.row_styles.get(&row),
+            self.cell_styles.get(&(row, column)),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(StyleOptions::default(), |style, over| style.layer(over))
+    }
+
+    /// Returns the options of a column, or the defaults if none were set.
+    fn column_options(&self, index: usize) -> ColumnOptions {
+        self.columns.get(index).cloned().unwrap_or_default()
+    }
 
-        println!("{}", border_line);
+    /// Resolves the alignment of a column, deciding `Alignment::Auto` from the column's cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based index of the column.
+    ///
+    /// # Returns
+    ///
+    /// `Alignment::Left`, `Alignment::Right` or `Alignment::Center`.
+    fn column_alignment(&self, index: usize) -> Alignment {
+        match self
+            .column_options(index)
+            .alignment
+            .unwrap_or(self.alignment)
+        {
+            Alignment::Auto => {
+                let mut cells = self
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.get(index))
+                    .map(|cell| strip_ansi(cell))
+                    .filter(|cell| !cell.trim().is_empty())
+                    .peekable();
+                if cells.peek().is_some() && cells.all(|cell| parse_number(&cell).is_some()) {
+                    Alignment::Right
+                } else {
+                    Alignment::Left
+                }
+            }
+            alignment => alignment,
+        }
+    }
 
-        // Print rows.
-        for row in &self.rows {
-            let row_str = row
+    /// Works out the width, alignment and overflow of every column.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_terminal` - Whether the output is a terminal, whose width the table then fits unless
+    ///   it sets a maximum width.
+    ///
+    /// # Returns
+    ///
+    /// The `Layout` the table is printed with.
+    fn layout(&self, is_terminal: bool) -> Layout<'_> {
+        let mut widths = self.column_widths();
+        let floors: Vec<usize> = (0..widths.len())
+            .map(|i| self.column_options(i).min_width.unwrap_or(0).max(1))
+            .collect();
+
+        // Shrink the widest columns, one column of the terminal at a time, until the table fits.
+        if let Some(budget) = self.width_budget(is_terminal) {
+            let chrome = Layout::chrome_width(&self.style, widths.len());
+            while widths.iter().sum::<usize>() + chrome > budget {
+                let widest = (0..widths.len())
+                    .filter(|&i| widths[i] > floors[i])
+                    .max_by_key(|&i| widths[i]);
+                match widest {
+                    Some(i) => widths[i] -= 1,
+                    None => break,
+                }
+            }
+        }
+
+        self.layout_with(widths)
+    }
+
+    /// Builds the layout for the given column widths, resolving each column's alignment and
+    /// overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `widths` - The width of each column.
+    ///
+    /// # Returns
+    ///
+    /// The `Layout` the table is printed with.
+    fn layout_with(&self, widths: Vec<usize>) -> Layout<'_> {
+        Layout {
+            alignments: (0..widths.len())
+                .map(|i| self.column_alignment(i))
+                .collect(),
+            vertical_alignments: (0..widths.len())
+                .map(|i| {
+                    self.column_options(i)
+                        .vertical_alignment
+                        .unwrap_or(self.vertical_alignment)
+                })
+                .collect(),
+            overflows: (0..widths.len())
+                .map(|i| self.column_options(i).overflow.unwrap_or(self.overflow))
+                .collect(),
+            widths,
+            style: &self.style,
+        }
+    }
+
+    /// Returns the total width the table must fit in.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_terminal` - Whether the output is a terminal, whose width the table then fits unless
+    ///   it sets a maximum width.
+    ///
+    /// # Returns
+    ///
+    /// The table's maximum width, or the terminal's width if none was set and the output is a
+    /// terminal, or `None` if the table's width is unconstrained.
+    fn width_budget(&self, is_terminal: bool) -> Option<usize> {
+        self.max_width.or_else(|| {
+            if !is_terminal {
+                return None;
+            }
+            terminal::size()
+                .ok()
+                .map(|(columns, _)| columns as usize)
+                .filter(|&columns| columns > 0)
+        })
+    }
+
+    /// Returns the number of columns, which is the length of the header or the longest row.
+    fn column_count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(Vec::len)
+            .chain([self.headers.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Calculates the natural display width of every column.
+    ///
+    /// Each column is as wide as its widest header, cell, subtotal or footer cell, within its
+    /// minimum and maximum width. Cells that span several columns are left out at first, and the
+    /// columns they span are then widened until they fit.
+    /// Rows with more cells than there are headers add columns with empty headers.
+    ///
+    /// # Returns
+    ///
+    /// The width of each column, in columns of the terminal.
+    fn column_widths(&self) -> Vec<usize> {
+        let subtotals: Vec<Vec<String>> = match self.group_column {
+            Some(column) if !self.subtotal_columns.is_empty() => self
+                .groups(column)
                 .iter()
-                .enumerate()
-                .map(|(i, cell)| {
-                    format!(
-                        " {}{} ",
-                        cell,
-                        " ".repeat(self.column_widths[i].saturating_sub(cell.len()))
-                    )
+                .map(|(_, rows)| self.subtotal_row(rows))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let footer = self.footer_row().unwrap_or_default();
+        let spans = |spans: Option<&usize>| spans.is_some_and(|&columns| columns > 1);
+
+        let mut widths = (0..self.column_count())
+            .map(|i| {
+                let options = self.column_options(i);
+                let rows = self
+                    .rows
+                    .iter()
+                    .enumerate()
+                    .filter(|&(r, _)| !spans(self.row_spans.get(&(r, i))))
+                    .map(|(_, row)| row);
+                let footer = footer.get(i).filter(|_| !spans(self.footer_spans.get(&i)));
+                let content_width = rows
+                    .chain(&subtotals)
+                    .filter_map(|row| row.get(i))
+                    .chain(self.headers.get(i))
+                    .chain(footer)
+                    .map(|text| cell_width(text))
+                    .max()
+                    .unwrap_or(0);
+                let width = content_width.max(options.min_width.unwrap_or(0));
+                options.max_width.map_or(width, |max| width.min(max))
+            })
+            .collect::<Vec<_>>();
+
+        let row_spans = self.row_spans.iter().map(|(&(row, column), &columns)| {
+            let cell = self.rows.get(row).and_then(|cells| cells.get(column));
+            (column, columns, cell)
+        });
+        let footer_spans = self
+            .footer_spans
+            .iter()
+            .map(|(&column, &columns)| (column, columns, footer.get(column)));
+        for (column, columns, cell) in row_spans.chain(footer_spans) {
+            if let Some(cell) = cell {
+                self.widen_for_span(&mut widths, column, columns, cell_width(cell));
+            }
+        }
+        widths
+    }
+
+    /// Widens the columns a cell spans until the cell fits, one column of the terminal at a time,
+    /// narrowest column first.
+    ///
+    /// # Arguments
+    ///
+    /// * `widths` - The width of each column, widened in place.
+    /// * `first` - The index of the first column spanned.
+    /// * `columns` - The number of columns spanned.
+    /// * `needed` - The width of the spanning cell's contents.
+    fn widen_for_span(&self, widths: &mut [usize], first: usize, columns: usize, needed: usize) {
+        let Some(available) = widths.len().checked_sub(first) else {
+            return;
+        };
+        let spanned = first..first + columns.min(available);
+        if spanned.len() < 2 {
+            return;
+        }
+
+        // The padding and separators between the columns are part of the cell's width.
+        let between = 2 * self.style.padding + display_width(&self.style.column_separator);
+        let gaps = between * (spanned.len() - 1);
+        while widths[spanned.clone()].iter().sum::<usize>() + gaps < needed {
+            let narrowest = spanned
+                .clone()
+                .filter(|&i| {
+                    self.column_options(i)
+                        .max_width
+                        .is_none_or(|max| widths[i] < max)
                 })
-                .collect::<Vec<_>>()
-                .join(" ");
-            println!("{}", row_str);
+                .min_by_key(|&i| widths[i]);
+            match narrowest {
+                Some(i) => widths[i] += 1,
+                None => break,
+            }
         }
+    }
+}
+
+/// Reads the number a cell holds, such as `42`, `-3.5`, `1,024` or `12%`.
+///
+/// # Arguments
+///
+/// * `text` - The cell's visible contents.
+///
+/// # Returns
+///
+/// The number, or `None` if the cell does not parse as one.
+fn parse_number(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let text = text.strip_suffix('%').unwrap_or(text);
+    let digits: String = text.chars().filter(|&c| c != ',' && c != '_').collect();
+    if !digits.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Styles a cell, re-applying the style after any reset inside pre-styled contents.
+///
+/// # Arguments
+///
+/// * `painter` - Decides whether and how the cell is styled.
+/// * `style` - The cell's style.
+/// * `cell` - The cell, including its padding.
+///
+/// # Returns
+///
+/// The styled cell.
+fn paint_cell(painter: &Painter, style: &StyleOptions, cell: &str) -> String {
+    split_after_resets(cell)
+        .into_iter()
+        .map(|piece| painter.paint(style, piece).to_string())
+        .collect()
+}
+
+/// Returns the style of subtotal and footer rows.
+///
+/// # Returns
+///
+/// Bold `StyleOptions`.
+fn summary_style() -> StyleOptions {
+    StyleOptions::default().with_attribute(Attribute::Bold)
+}
 
-        println!("{}", border_line);
+/// Returns the header style used when neither the table nor a theme sets one.
+///
+/// # Returns
+///
+/// Bold green `StyleOptions`.
+fn default_header_style() -> StyleOptions {
+    StyleOptions {
+        foreground: Some(Color::Green),
+        ..Default::default()
     }
+    .with_attribute(Attribute::Bold)
 }
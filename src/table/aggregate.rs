@@ -0,0 +1,60 @@
+use super::parse_number;
+use crate::width::strip_ansi;
+
+/// A summary of a column's cells, shown in the table's footer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// The total of the column's numbers.
+    Sum,
+    /// The mean of the column's numbers.
+    Average,
+    /// The number of non-empty cells.
+    Count,
+    /// The smallest of the column's numbers.
+    Min,
+    /// The largest of the column's numbers.
+    Max,
+}
+
+impl Aggregate {
+    /// Computes the aggregate of a column's cells.
+    ///
+    /// Cells that are not numbers are left out, except by `Aggregate::Count`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - The column's cells.
+    ///
+    /// # Returns
+    ///
+    /// The result, with as many decimal places as the most precise number (and at least two for
+    /// averages), or an empty string if there is nothing to average, minimize or maximize.
+    pub(super) fn compute<'c>(self, cells: impl Iterator<Item = &'c str>) -> String {
+        let mut numbers = Vec::new();
+        let mut count = 0;
+        let mut decimals = 0;
+        for text in cells.map(strip_ansi) {
+            if !text.trim().is_empty() {
+                count += 1;
+            }
+            if let Some(number) = parse_number(&text) {
+                numbers.push(number);
+                let fraction = text.trim().trim_end_matches('%').split_once('.');
+                decimals = decimals.max(fraction.map_or(0, |(_, digits)| digits.len()));
+            }
+        }
+
+        let result = match self {
+            Aggregate::Count => return count.to_string(),
+            Aggregate::Sum => Some(numbers.iter().sum()),
+            Aggregate::Average if numbers.is_empty() => None,
+            Aggregate::Average => {
+                decimals = decimals.max(2);
+                Some(numbers.iter().sum::<f64>() / numbers.len() as f64)
+            }
+            Aggregate::Min => numbers.iter().copied().reduce(f64::min),
+            Aggregate::Max => numbers.iter().copied().reduce(f64::max),
+        };
+        result.map_or_else(String::new, |value| format!("{:.*}", decimals, value))
+    }
+}
@@ -0,0 +1,434 @@
+use super::{parse_number, Aggregate, Table};
+use crate::error::Error;
+use crate::width::strip_ansi;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// The direction rows are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Smallest first.
+    #[default]
+    Ascending,
+    /// Largest first.
+    Descending,
+}
+
+impl SortOrder {
+    /// Orients a comparison in this direction.
+    fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    }
+}
+
+impl Table {
+    /// Sorts the rows by the text of a column.
+    ///
+    /// Cells are compared as strings, ignoring any escape codes, and rows that compare equal keep
+    /// their order. Row and cell styles move with their rows. A column beyond the last one is
+    /// empty in every row, so the rows keep their order; use [`Table::try_sort_by_column`] to
+    /// catch this.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column to sort by.
+    /// * `order` - Whether to sort ascending or descending.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with its rows sorted.
+    pub fn sort_by_column(self, column: usize, order: SortOrder) -> Table {
+        self.sort_by(|a, b| {
+            order.apply(strip_ansi(cell(a, column)).cmp(&strip_ansi(cell(b, column))))
+        })
+    }
+
+    /// Sorts the rows by the text of a column, checking that the column exists.
+    ///
+    /// See [`Table::sort_by_column`] for how rows are compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column to sort by.
+    /// * `order` - Whether to sort ascending or descending.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with its rows sorted, or `Error::InvalidInput` if the column does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `column` is not less than the number of columns.
+    pub fn try_sort_by_column(self, column: usize, order: SortOrder) -> Result<Table, Error> {
+        self.check_column(column)?;
+        Ok(self.sort_by_column(column, order))
+    }
+
+    /// Sorts the rows by the numeric value of a column.
+    ///
+    /// Numbers are read the same way as for `Alignment::Auto`, so `1,024` and `12%` sort as
+    /// numbers. Cells that are not numbers sort after every number, in either order. As with
+    /// [`Table::sort_by_column`], a column beyond the last one leaves the rows in their order;
+    /// use [`Table::try_sort_by_column_numeric`] to catch this.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column to sort by.
+    /// * `order` - Whether to sort ascending or descending.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with its rows sorted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{SortOrder, Table};
+    ///
+    /// let table = Table::new(
+    ///     vec!["Item", "Stock"],
+    ///     vec![vec!["bolts", "1,200"], vec!["nuts", "85"], vec!["gears", "n/a"], vec!["rivets", "310"]],
+    /// )
+    /// .sort_by_column_numeric(1, SortOrder::Descending);
+    ///
+    /// let items: Vec<&str> = table.rows().iter().map(|row| row[0].as_str()).collect();
+    /// assert_eq!(items, ["bolts", "rivets", "nuts", "gears"]);
+    /// ```
+    pub fn sort_by_column_numeric(self, column: usize, order: SortOrder) -> Table {
+        self.sort_by(|a, b| {
+            let a = parse_number(&strip_ansi(cell(a, column)));
+            let b = parse_number(&strip_ansi(cell(b, column)));
+            match (a, b) {
+                (Some(a), Some(b)) => order.apply(a.total_cmp(&b)),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        })
+    }
+
+    /// Sorts the rows by the numeric value of a column, checking that the column exists.
+    ///
+    /// See [`Table::sort_by_column_numeric`] for how numbers are read.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column to sort by.
+    /// * `order` - Whether to sort ascending or descending.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with its rows sorted, or `Error::InvalidInput` if the column does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `column` is not less than the number of columns.
+    pub fn try_sort_by_column_numeric(
+        self,
+        column: usize,
+        order: SortOrder,
+    ) -> Result<Table, Error> {
+        self.check_column(column)?;
+        Ok(self.sort_by_column_numeric(column, order))
+    }
+
+    /// Sorts the rows with a custom comparator.
+    ///
+    /// The sort is stable, and row and cell styles move with their rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - Compares two rows, given their cells.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with its rows sorted.
+    pub fn sort_by<F>(mut self, mut compare: F) -> Table
+    where
+        F: FnMut(&[String], &[String]) -> Ordering,
+    {
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        order.sort_by(|&a, &b| compare(&self.rows[a], &self.rows[b]));
+        self.reorder(&order);
+        self
+    }
+
+    /// Keeps only the rows that match a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Returns `true` for the rows to keep, given their cells.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` without the rows that did not match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    ///
+    /// let table = Table::new(
+    ///     vec!["Item", "Stock"],
+    ///     vec![vec!["bolts", "1200"], vec!["nuts", "0"], vec!["gears", "40"]],
+    /// )
+    /// .filter(|row| row[1] != "0");
+    ///
+    /// assert_eq!(table.rows().len(), 2);
+    /// ```
+    pub fn filter<F>(mut self, mut predicate: F) -> Table
+    where
+        F: FnMut(&[String]) -> bool,
+    {
+        let kept: Vec<usize> = (0..self.rows.len())
+            .filter(|&i| predicate(&self.rows[i]))
+            .collect();
+        self.reorder(&kept);
+        self
+    }
+
+    /// Groups the rows by the value of a column.
+    ///
+    /// Each group is printed under a line naming the column and the shared value, with groups in
+    /// the order their first row appears. Sort the table first to order the rows within, and
+    /// across, groups. Exports are not grouped. A column beyond the last one is empty in every
+    /// row, so all rows end up in a single group with an empty title; use
+    /// [`Table::try_group_by`] to catch this.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column to group by.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with grouped rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{SortOrder, Table};
+    ///
+    /// let table = Table::new(
+    ///     vec!["Category", "Item", "Stock"],
+    ///     vec![
+    ///         vec!["Hardware", "bolts", "1200"],
+    ///         vec!["Tools", "wrench", "15"],
+    ///         vec!["Hardware", "nuts", "300"],
+    ///     ],
+    /// )
+    /// .sort_by_column(1, SortOrder::Ascending)
+    /// .group_by(0)
+    /// .with_subtotals([2]);
+    ///
+    /// let rendered = table.render();
+    /// let lines: Vec<&str> = rendered.lines().map(str::trim_end).collect();
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         "---------- -------- -------",
+    ///         " Category   Item     Stock",
+    ///         "---------- -------- -------",
+    ///         " Category: Hardware",
+    ///         " Hardware   bolts    1200",
+    ///         " Hardware   nuts     300",
+    ///         " Subtotal            1500",
+    ///         "---------- -------- -------",
+    ///         " Category: Tools",
+    ///         " Tools      wrench   15",
+    ///         " Subtotal            15",
+    ///         "---------- -------- -------",
+    ///     ]
+    /// );
+    /// ```
+    pub fn group_by(mut self, column: usize) -> Table {
+        self.group_column = Some(column);
+        self
+    }
+
+    /// Groups the rows by the value of a column, checking that the column exists.
+    ///
+    /// See [`Table::group_by`] for how groups are printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column to group by.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with grouped rows, or `Error::InvalidInput` if the column does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `column` is not less than the number of columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    ///
+    /// let table = Table::new(vec!["Category", "Item"], vec![vec!["Tools", "wrench"]]);
+    ///
+    /// let error = table.try_group_by(2).unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "invalid input: column 3 does not exist; the table has 2 columns"
+    /// );
+    /// ```
+    pub fn try_group_by(self, column: usize) -> Result<Table, Error> {
+        self.check_column(column)?;
+        Ok(self.group_by(column))
+    }
+
+    /// Adds a subtotal line after each group, summing the numbers in the given columns.
+    ///
+    /// Only has an effect on grouped tables. Cells that are not numbers count as zero, and
+    /// columns beyond the last one are ignored; use [`Table::try_with_subtotals`] to catch them.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The zero-based indexes of the columns to sum.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with subtotals.
+    pub fn with_subtotals(mut self, columns: impl IntoIterator<Item = usize>) -> Table {
+        self.subtotal_columns = columns.into_iter().collect();
+        self
+    }
+
+    /// Adds a subtotal line after each group, checking that every column exists.
+    ///
+    /// See [`Table::with_subtotals`] for how subtotals are computed.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The zero-based indexes of the columns to sum.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with subtotals, or `Error::InvalidInput` naming the first column that does
+    /// not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any column is not less than the number of columns.
+    pub fn try_with_subtotals(
+        self,
+        columns: impl IntoIterator<Item = usize>,
+    ) -> Result<Table, Error> {
+        let columns: Vec<usize> = columns.into_iter().collect();
+        for &column in &columns {
+            self.check_column(column)?;
+        }
+        Ok(self.with_subtotals(columns))
+    }
+
+    /// Checks that a column exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or `Error::InvalidInput` if the column is beyond the last one.
+    fn check_column(&self, column: usize) -> Result<(), Error> {
+        let count = self.column_count();
+        if column < count {
+            return Ok(());
+        }
+        Err(Error::InvalidInput(format!(
+            "column {} does not exist; the table has {} columns",
+            column + 1,
+            count
+        )))
+    }
+
+    /// Splits the rows into groups by the value of a column.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column to group by.
+    ///
+    /// # Returns
+    ///
+    /// Each group's value and the indexes of its rows, in the order the groups first appear.
+    pub(super) fn groups(&self, column: usize) -> Vec<(String, Vec<usize>)> {
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        for (i, row) in self.rows.iter().enumerate() {
+            let value = cell(row, column);
+            match positions.get(value) {
+                Some(&position) => groups[position].1.push(i),
+                None => {
+                    positions.insert(value, groups.len());
+                    groups.push((value.to_string(), vec![i]));
+                }
+            }
+        }
+        groups
+    }
+
+    /// Builds the subtotal line of a group.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The indexes of the group's rows.
+    ///
+    /// # Returns
+    ///
+    /// The sums of the subtotal columns, labelled `Subtotal` in the first column not summed.
+    pub(super) fn subtotal_row(&self, rows: &[usize]) -> Vec<String> {
+        let mut cells = vec![String::new(); self.column_count()];
+        for &column in &self.subtotal_columns {
+            if let Some(subtotal) = cells.get_mut(column) {
+                *subtotal =
+                    Aggregate::Sum.compute(rows.iter().map(|&i| cell(&self.rows[i], column)));
+            }
+        }
+        if let Some(label) = (0..cells.len()).find(|column| !self.subtotal_columns.contains(column))
+        {
+            cells[label] = "Subtotal".to_string();
+        }
+        cells
+    }
+
+    /// Keeps the rows at the given indexes, in the given order, along with their styles and spans.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The indexes of the rows to keep.
+    fn reorder(&mut self, order: &[usize]) {
+        let position: HashMap<usize, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(new, &old)| (old, new))
+            .collect();
+
+        let mut rows: Vec<Option<Vec<String>>> = std::mem::take(&mut self.rows)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.rows = order.iter().filter_map(|&i| rows[i].take()).collect();
+        self.row_styles = std::mem::take(&mut self.row_styles)
+            .into_iter()
+            .filter_map(|(row, style)| Some((*position.get(&row)?, style)))
+            .collect();
+        self.cell_styles = std::mem::take(&mut self.cell_styles)
+            .into_iter()
+            .filter_map(|((row, column), style)| Some(((*position.get(&row)?, column), style)))
+            .collect();
+        self.row_spans = std::mem::take(&mut self.row_spans)
+            .into_iter()
+            .filter_map(|((row, column), span)| Some(((*position.get(&row)?, column), span)))
+            .collect();
+    }
+}
+
+/// Returns a cell of a row, empty if the row is missing it.
+fn cell(row: &[String], column: usize) -> &str {
+    row.get(column).map_or("", String::as_str)
+}
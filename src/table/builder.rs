@@ -0,0 +1,285 @@
+use super::{
+    Aggregate, Alignment, ColumnOptions, Expanded, Overflow, RaggedRows, Table, TableStyle,
+    VerticalAlignment,
+};
+use crate::color::ColorChoice;
+use crate::error::Error;
+use crate::style_options::StyleOptions;
+use std::fmt::Display;
+
+/// A builder for `Table`, created with [`Table::builder`].
+///
+/// Headers and cells accept anything that implements `Display`, so numbers and formatted values
+/// can be added without keeping their `String`s alive elsewhere.
+#[derive(Debug, Clone, Default)]
+pub struct TableBuilder {
+    table: Table,
+    ragged_rows: RaggedRows,
+}
+
+impl TableBuilder {
+    /// Creates a builder for an empty table.
+    ///
+    /// # Returns
+    ///
+    /// A `TableBuilder` without headers or rows.
+    pub fn new() -> TableBuilder {
+        TableBuilder::default()
+    }
+
+    /// Sets the headers of the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The header of each column.
+    pub fn header<I, T>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        self.table.headers = headers.into_iter().map(|h| h.to_string()).collect();
+        self
+    }
+
+    /// Appends a row to the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The cells of the row.
+    pub fn row<I, T>(mut self, row: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        self.table.add_row(row);
+        self
+    }
+
+    /// Appends several rows to the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows to append, each an iterator of cells.
+    pub fn rows<R, I, T>(mut self, rows: R) -> Self
+    where
+        R: IntoIterator<Item = I>,
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        for row in rows {
+            self.table.add_row(row);
+        }
+        self
+    }
+
+    /// Sets the cells of the footer, printed below the rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - The footer's cells, one per column.
+    pub fn footer<I, T>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        self.table = self.table.with_footer(cells);
+        self
+    }
+
+    /// Makes a footer cell span several columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the first column the cell spans.
+    /// * `columns` - The number of columns the cell spans.
+    pub fn footer_span(mut self, column: usize, columns: usize) -> Self {
+        self.table = self.table.with_footer_span(column, columns);
+        self
+    }
+
+    /// Shows an aggregate of a column's cells in the footer.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column to aggregate.
+    /// * `aggregate` - The aggregate to show, such as `Aggregate::Sum`.
+    pub fn aggregate(mut self, column: usize, aggregate: Aggregate) -> Self {
+        self.table = self.table.with_aggregate(column, aggregate);
+        self
+    }
+
+    /// Sets the options of a single column.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based index of the column.
+    /// * `options` - The alignment, width limits and header style of the column.
+    pub fn column(mut self, index: usize, options: ColumnOptions) -> Self {
+        self.table = self.table.with_column(index, options);
+        self
+    }
+
+    /// Sets the alignment of every column that does not set its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment` - The default alignment, such as `Alignment::Auto` to right-align numbers.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.table.alignment = alignment;
+        self
+    }
+
+    /// Sets where cells are placed in rows taller than them, for columns that do not set their own.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertical_alignment` - The default vertical alignment, such as
+    ///   `VerticalAlignment::Middle`.
+    pub fn vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.table.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Sets whether the table is printed as a list of `header: value` records instead of rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `expanded` - `Expanded::On`, `Expanded::Off`, or `Expanded::Auto` to expand only tables
+    ///   that are too wide.
+    pub fn expanded(mut self, expanded: Expanded) -> Self {
+        self.table.expanded = expanded;
+        self
+    }
+
+    /// Sets what happens to cells that are wider than their column.
+    ///
+    /// # Arguments
+    ///
+    /// * `overflow` - Whether long cells wrap (the default) or are truncated with `…`.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.table.overflow = overflow;
+        self
+    }
+
+    /// Sets the total width the table must fit in, instead of the terminal's width.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_width` - The maximum width of the table, including its borders.
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.table.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the borders and separators the table is drawn with.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The table style, such as `TableStyle::rounded()`.
+    pub fn style(mut self, style: TableStyle) -> Self {
+        self.table.style = style;
+        self
+    }
+
+    /// Sets the style used for the header row.
+    ///
+    /// # Arguments
+    ///
+    /// * `header_style` - The colors and text attributes to apply to each header.
+    pub fn header_style(mut self, header_style: StyleOptions) -> Self {
+        self.table.header_style = Some(header_style);
+        self
+    }
+
+    /// Sets the style of every cell outside the header.
+    ///
+    /// # Arguments
+    ///
+    /// * `body_style` - The colors and text attributes to apply to every cell.
+    pub fn body_style(mut self, body_style: StyleOptions) -> Self {
+        self.table.body_style = Some(body_style);
+        self
+    }
+
+    /// Stripes the table by styling every other row, starting with the second.
+    ///
+    /// # Arguments
+    ///
+    /// * `zebra_style` - The style of the striped rows, usually just a background color.
+    pub fn zebra(mut self, zebra_style: StyleOptions) -> Self {
+        self.table.zebra_style = Some(zebra_style);
+        self
+    }
+
+    /// Sets the style of a whole row.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero-based index of the row, not counting the header.
+    /// * `style` - The colors and text attributes to apply to the row's cells.
+    pub fn row_style(mut self, row: usize, style: StyleOptions) -> Self {
+        self.table.row_styles.insert(row, style);
+        self
+    }
+
+    /// Sets the style of a single cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero-based index of the row, not counting the header.
+    /// * `column` - The zero-based index of the column.
+    /// * `style` - The colors and text attributes to apply to the cell.
+    pub fn cell_style(mut self, row: usize, column: usize, style: StyleOptions) -> Self {
+        self.table.cell_styles.insert((row, column), style);
+        self
+    }
+
+    /// Sets whether the table is styled with colors and text attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `color_choice` - The color choice to use.
+    pub fn color_choice(mut self, color_choice: ColorChoice) -> Self {
+        self.table.color_choice = color_choice;
+        self
+    }
+
+    /// Sets what `try_build` does with rows that have a different number of cells than there are
+    /// headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `ragged_rows` - Whether to extend the headers, pad and cut rows, or fail.
+    pub fn ragged_rows(mut self, ragged_rows: RaggedRows) -> Self {
+        self.ragged_rows = ragged_rows;
+        self
+    }
+
+    /// Creates the configured `Table`.
+    ///
+    /// Rows are kept as they were added; when printed, short rows are padded and long rows add
+    /// columns with empty headers. A builder without headers creates a headerless table.
+    ///
+    /// # Returns
+    ///
+    /// The built `Table`.
+    pub fn build(self) -> Table {
+        self.table
+    }
+
+    /// Creates the configured `Table`, making every row as long as the header according to the
+    /// `ragged_rows` policy.
+    ///
+    /// # Returns
+    ///
+    /// The built `Table`, or `Error::InvalidInput` naming the first row that does not fit if the
+    /// policy is `RaggedRows::Error`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the policy is `RaggedRows::Error` and a row's length differs from the
+    /// number of headers.
+    pub fn try_build(self) -> Result<Table, Error> {
+        self.table.conform_rows(self.ragged_rows)
+    }
+}
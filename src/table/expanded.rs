@@ -0,0 +1,197 @@
+use super::layout::Layout;
+use super::{paint_cell, Overflow, Table};
+use crate::color::Painter;
+use crate::style_options::StyleOptions;
+use crate::width::{display_width, padding, split_lines, truncate, wrap};
+use std::io::{self, Write};
+
+/// Whether a table is printed with one line per row or as a list of records, like psql's `\x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Expanded {
+    /// Rows are printed side by side under the header.
+    #[default]
+    Off,
+    /// Each row is printed as a block of `header: value` lines.
+    On,
+    /// Rows are printed as records only if the table is too wide to fit without shrinking its
+    /// columns.
+    Auto,
+}
+
+impl Table {
+    /// Sets whether the table is printed as a list of records instead of rows.
+    ///
+    /// In the expanded layout, each row becomes a block with one `header: value` line per
+    /// column, and blocks are separated by `-[ RECORD n ]-` lines. Long values wrap to the width
+    /// of the table. Groups and footers are not printed in this layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `expanded` - `Expanded::On`, `Expanded::Off`, or `Expanded::Auto` to expand only tables
+    ///   that are too wide.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new layout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{Expanded, Table};
+    ///
+    /// let table = Table::new(
+    ///     vec!["Name", "Role"],
+    ///     vec![vec!["Ada", "Engineer"], vec!["Grace", "Rear Admiral"]],
+    /// )
+    /// .with_expanded(Expanded::On);
+    ///
+    /// let rendered = table.render();
+    /// let lines: Vec<&str> = rendered.lines().collect();
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         "-[ RECORD 1 ]-----",
+    ///         "Name: Ada",
+    ///         "Role: Engineer",
+    ///         "-[ RECORD 2 ]-----",
+    ///         "Name: Grace",
+    ///         "Role: Rear Admiral",
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_expanded(mut self, expanded: Expanded) -> Table {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Returns whether the table is printed as a list of records.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_terminal` - Whether the output is a terminal, whose width the table then fits unless
+    ///   it sets a maximum width.
+    pub(super) fn is_expanded(&self, is_terminal: bool) -> bool {
+        match self.expanded {
+            Expanded::Off => false,
+            Expanded::On => true,
+            Expanded::Auto => {
+                let widths = self.column_widths();
+                let natural =
+                    widths.iter().sum::<usize>() + Layout::chrome_width(&self.style, widths.len());
+                self.width_budget(is_terminal)
+                    .is_some_and(|budget| natural > budget)
+            }
+        }
+    }
+
+    /// Writes every row as a block of `header: value` lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the records to.
+    /// * `painter` - Decides whether and how the labels and values are styled.
+    /// * `fallback_header_style` - The label style to use if the table does not set a header
+    ///   style.
+    /// * `is_terminal` - Whether the output is a terminal, whose width the table then fits unless
+    ///   it sets a maximum width.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    pub(super) fn write_expanded(
+        &self,
+        out: &mut impl Write,
+        painter: &Painter,
+        fallback_header_style: &StyleOptions,
+        is_terminal: bool,
+    ) -> io::Result<()> {
+        let labels: Vec<String> = (0..self.column_count())
+            .map(|i| match self.headers.get(i).filter(|h| !h.is_empty()) {
+                Some(header) => header.clone(),
+                None => format!("column {}", i + 1),
+            })
+            .collect();
+        let label_width = labels.iter().map(|l| display_width(l)).max().unwrap_or(0);
+        let indent = " ".repeat(label_width + 2);
+
+        // Values wrap to whatever width is left after the labels.
+        let budget = self.width_budget(is_terminal);
+        let value_width = budget.map(|budget| budget.saturating_sub(label_width + 2).max(1));
+        let records: Vec<Vec<Vec<String>>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                (0..labels.len())
+                    .map(|i| {
+                        let value = row.get(i).map_or("", String::as_str);
+                        let overflow = self.column_options(i).overflow.unwrap_or(self.overflow);
+                        fit_value(value, value_width, overflow)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let widest_value = records
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|line| display_width(line))
+            .max()
+            .unwrap_or(0);
+        let mut line_width = label_width + 2 + widest_value;
+        if let Some(budget) = budget {
+            line_width = line_width.min(budget);
+        }
+
+        let table_header_style = self.header_style.as_ref().unwrap_or(fallback_header_style);
+        for (r, record) in records.iter().enumerate() {
+            let marker = format!("-[ RECORD {} ]", r + 1);
+            let fill = "-".repeat(line_width.saturating_sub(display_width(&marker)));
+            writeln!(out, "{}{}", marker, fill)?;
+
+            for (i, lines) in record.iter().enumerate() {
+                let label_style = self.column_options(i).header_style;
+                let label = format!("{}:", labels[i]);
+                let label = format!("{}{}", label, padding(&label, label_width + 1));
+                let value_style = self.cell_style(r, i);
+                for (l, line) in lines.iter().enumerate() {
+                    let prefix = if l == 0 {
+                        paint_cell(
+                            painter,
+                            label_style.as_ref().unwrap_or(table_header_style),
+                            &label,
+                        ) + " "
+                    } else {
+                        indent.clone()
+                    };
+                    writeln!(out, "{}{}", prefix, paint_cell(painter, &value_style, line))?;
+                }
+            }
+        }
+        out.flush()
+    }
+}
+
+/// Fits a value to the width left beside the labels.
+///
+/// # Arguments
+///
+/// * `value` - The cell's contents.
+/// * `width` - The width available, or `None` if values never wrap.
+/// * `overflow` - What to do if a line is wider than `width`.
+///
+/// # Returns
+///
+/// The lines of the value.
+fn fit_value(value: &str, width: Option<usize>, overflow: Overflow) -> Vec<String> {
+    split_lines(value)
+        .into_iter()
+        .flat_map(|line| match width {
+            Some(width) if display_width(&line) > width => match overflow {
+                Overflow::Wrap => wrap(&line, width),
+                Overflow::Truncate => vec![truncate(&line, width)],
+            },
+            _ => vec![line],
+        })
+        .collect()
+}
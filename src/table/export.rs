@@ -0,0 +1,364 @@
+use super::{Alignment, Table};
+use crate::width::strip_ansi;
+use std::fmt::Write;
+
+impl Table {
+    /// Exports the table as a GitHub-flavored Markdown pipe table.
+    ///
+    /// Column alignments are kept with `:---`, `---:` and `:---:` markers. Pipes in cells are
+    /// escaped, line breaks become `<br>`, and any escape codes are removed. The footer, with its
+    /// aggregates computed, is the last row; groups are not exported.
+    ///
+    /// # Returns
+    ///
+    /// The table as Markdown, ending with a newline.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{Alignment, Table};
+    ///
+    /// let table = Table::new(vec!["Name", "Score"], vec![vec!["Ada", "97"], vec!["Grace", "88"]])
+    ///     .with_alignment(Alignment::Auto);
+    ///
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     "| Name | Score |\n| --- | ---: |\n| Ada | 97 |\n| Grace | 88 |\n"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        let escape = |cell: &str| {
+            strip_ansi(cell)
+                .replace('|', "\\|")
+                .replace("\r\n", "<br>")
+                .replace('\n', "<br>")
+        };
+
+        let write_line = |markdown: &mut String, cells: Vec<String>| {
+            markdown.push_str("| ");
+            markdown.push_str(&cells.join(" | "));
+            markdown.push_str(" |\n");
+        };
+
+        write_line(&mut markdown, self.export_headers().map(escape).collect());
+        write_line(
+            &mut markdown,
+            (0..self.column_count())
+                .map(|i| match self.column_alignment(i) {
+                    Alignment::Right => "---:",
+                    Alignment::Center => ":---:",
+                    Alignment::Left | Alignment::Auto => "---",
+                })
+                .map(str::to_string)
+                .collect(),
+        );
+        for row in self.rows.iter().chain(&self.footer_row()) {
+            write_line(&mut markdown, self.export_cells(row).map(escape).collect());
+        }
+        markdown
+    }
+
+    /// Exports the table as comma-separated values.
+    ///
+    /// Fields containing commas, quotes or line breaks are quoted, with quotes doubled, as
+    /// described by RFC 4180. The header, if the table has one, is the first record and the
+    /// footer, with its aggregates computed, the last; groups are not exported. Records end with
+    /// `\n`.
+    ///
+    /// # Returns
+    ///
+    /// The table as CSV.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{Aggregate, Table};
+    ///
+    /// let table = Table::new(
+    ///     vec!["City", "Note"],
+    ///     vec![vec!["Paris", "Fine, \"sunny\""], vec!["Oslo", "Snow"]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     table.to_csv(),
+    ///     "City,Note\nParis,\"Fine, \"\"sunny\"\"\"\nOslo,Snow\n"
+    /// );
+    ///
+    /// let table = Table::new(vec!["Item", "Amount"], vec![vec!["Hosting", "180"], vec!["Support", "200"]])
+    ///     .with_footer(["Total"])
+    ///     .with_aggregate(1, Aggregate::Sum);
+    ///
+    /// assert_eq!(table.to_csv(), "Item,Amount\nHosting,180\nSupport,200\nTotal,380\n");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        self.to_delimited(',')
+    }
+
+    /// Exports the table as tab-separated values.
+    ///
+    /// Fields containing tabs, quotes or line breaks are quoted the same way as in
+    /// [`Table::to_csv`], and the footer is likewise the last record.
+    ///
+    /// # Returns
+    ///
+    /// The table as TSV.
+    pub fn to_tsv(&self) -> String {
+        self.to_delimited('\t')
+    }
+
+    /// Exports the table as a JSON array with one object per row, keyed by header.
+    ///
+    /// Every value is a string, exactly as it appears in the table. Columns without a header are
+    /// keyed by their position, such as `"column 3"`. Keys are kept unique by numbering repeated
+    /// headers, so two `a` columns become `"a"` and `"a 2"`. Only the rows are exported: the
+    /// footer and its aggregates are not a row of data, so they are left out, as are groups.
+    ///
+    /// # Returns
+    ///
+    /// The table as JSON, with each row's object on a line of its own.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    ///
+    /// let table = Table::new(vec!["Name", "Age"], vec![vec!["Ada", "36"]]);
+    /// assert_eq!(table.to_json(), "[\n  {\"Name\": \"Ada\", \"Age\": \"36\"}\n]\n");
+    ///
+    /// let table = Table::new(vec!["Score", "Score"], vec![vec!["7", "9"]]);
+    /// assert_eq!(table.to_json(), "[\n  {\"Score\": \"7\", \"Score 2\": \"9\"}\n]\n");
+    /// ```
+    pub fn to_json(&self) -> String {
+        let names: Vec<String> = self
+            .export_headers()
+            .enumerate()
+            .map(|(i, header)| {
+                if header.is_empty() {
+                    format!("column {}", i + 1)
+                } else {
+                    header.to_string()
+                }
+            })
+            .collect();
+
+        // Number repeated names, skipping any numbers another header already uses.
+        let mut keys: Vec<String> = Vec::with_capacity(names.len());
+        for name in &names {
+            let key = if keys.contains(name) {
+                (2..)
+                    .map(|n| format!("{} {}", name, n))
+                    .find(|key| !keys.contains(key) && !names.contains(key))
+                    .expect("A free number is always found")
+            } else {
+                name.clone()
+            };
+            keys.push(key);
+        }
+
+        let objects: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let fields: Vec<String> = keys
+                    .iter()
+                    .zip(self.export_cells(row))
+                    .map(|(key, cell)| format!("{}: {}", json_string(key), json_string(cell)))
+                    .collect();
+                format!("  {{{}}}", fields.join(", "))
+            })
+            .collect();
+
+        if objects.is_empty() {
+            "[]\n".to_string()
+        } else {
+            format!("[\n{}\n]\n", objects.join(",\n"))
+        }
+    }
+
+    /// Exports the table as an HTML `<table>` with a `<thead>`, unless it has no headers, a
+    /// `<tbody>`, and a `<tfoot>` if it has a footer.
+    ///
+    /// Cells are HTML-escaped, line breaks become `<br>`, and columns that are not left-aligned
+    /// get a `text-align` style. The footer's aggregates are computed; groups are not exported.
+    ///
+    /// # Returns
+    ///
+    /// The table as an HTML fragment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    ///
+    /// let table = Table::new(vec!["Tag"], vec![vec!["<b>"]]);
+    /// let html = table.to_html();
+    ///
+    /// assert!(html.starts_with("<table>\n  <thead>\n    <tr>\n      <th>Tag</th>\n"));
+    /// assert!(html.contains("<td>&lt;b&gt;</td>"));
+    ///
+    /// let html = Table::new(vec!["Qty"], vec![vec!["2"], vec!["3"]])
+    ///     .with_footer(["5"])
+    ///     .to_html();
+    /// assert!(html.ends_with("  <tfoot>\n    <tr>\n      <td>5</td>\n    </tr>\n  </tfoot>\n</table>\n"));
+    /// ```
+    pub fn to_html(&self) -> String {
+        let attributes: Vec<&str> = (0..self.column_count())
+            .map(|i| match self.column_alignment(i) {
+                Alignment::Right => " style=\"text-align: right\"",
+                Alignment::Center => " style=\"text-align: center\"",
+                Alignment::Left | Alignment::Auto => "",
+            })
+            .collect();
+
+        let mut html = String::from("<table>\n");
+        if !self.headers.is_empty() {
+            html.push_str("  <thead>\n");
+            write_html_row(&mut html, "th", self.export_headers(), &attributes);
+            html.push_str("  </thead>\n");
+        }
+        html.push_str("  <tbody>\n");
+        for row in &self.rows {
+            write_html_row(&mut html, "td", self.export_cells(row), &attributes);
+        }
+        html.push_str("  </tbody>\n");
+        if let Some(footer) = self.footer_row() {
+            html.push_str("  <tfoot>\n");
+            write_html_row(&mut html, "td", self.export_cells(&footer), &attributes);
+            html.push_str("  </tfoot>\n");
+        }
+        html.push_str("</table>\n");
+        html
+    }
+
+    /// Exports the table as delimiter-separated values, quoting fields where needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - The character between fields.
+    ///
+    /// # Returns
+    ///
+    /// One record per line, starting with the header and ending with the footer if there are
+    /// any.
+    fn to_delimited(&self, delimiter: char) -> String {
+        let quote = |field: &str| {
+            let field = strip_ansi(field);
+            if field.contains([delimiter, '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.into_owned()
+            }
+        };
+        let separator = delimiter.to_string();
+
+        let mut records = Vec::new();
+        if !self.headers.is_empty() {
+            records.push(
+                self.export_headers()
+                    .map(quote)
+                    .collect::<Vec<_>>()
+                    .join(&separator),
+            );
+        }
+        for row in self.rows.iter().chain(&self.footer_row()) {
+            records.push(
+                self.export_cells(row)
+                    .map(quote)
+                    .collect::<Vec<_>>()
+                    .join(&separator),
+            );
+        }
+
+        let mut delimited = records.join("\n");
+        delimited.push('\n');
+        delimited
+    }
+
+    /// Returns the header of every column, empty for columns without one.
+    fn export_headers(&self) -> impl Iterator<Item = &str> {
+        self.export_cells(&self.headers)
+    }
+
+    /// Returns every cell of a row, empty for columns the row is missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row's cells.
+    fn export_cells<'r>(&self, row: &'r [String]) -> impl Iterator<Item = &'r str> {
+        (0..self.column_count()).map(move |i| row.get(i).map_or("", String::as_str))
+    }
+}
+
+/// Writes a `<tr>` element with one cell per column.
+///
+/// # Arguments
+///
+/// * `html` - The HTML to append to.
+/// * `tag` - The cell tag, `th` or `td`.
+/// * `cells` - The contents of each cell.
+/// * `attributes` - The attributes of each column's cells.
+fn write_html_row<'c>(
+    html: &mut String,
+    tag: &str,
+    cells: impl Iterator<Item = &'c str>,
+    attributes: &[&str],
+) {
+    html.push_str("    <tr>\n");
+    for (cell, attributes) in cells.zip(attributes) {
+        let _ = writeln!(
+            html,
+            "      <{tag}{attributes}>{}</{tag}>",
+            html_escape(cell)
+        );
+    }
+    html.push_str("    </tr>\n");
+}
+
+/// Escapes text for use inside HTML, turning line breaks into `<br>`.
+///
+/// # Arguments
+///
+/// * `text` - The text to escape.
+///
+/// # Returns
+///
+/// The escaped text, with any escape codes removed.
+fn html_escape(text: &str) -> String {
+    strip_ansi(text)
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// Encodes text as a JSON string literal.
+///
+/// # Arguments
+///
+/// * `text` - The text to encode.
+///
+/// # Returns
+///
+/// The quoted and escaped string, with any escape codes removed.
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in strip_ansi(text).chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
@@ -0,0 +1,117 @@
+use super::{Aggregate, Table};
+use std::fmt::Display;
+
+impl Table {
+    /// Sets the cells of the footer, a row printed below the others and set apart like the
+    /// header. Exports other than [`Table::to_json`] include it as their last row.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - The footer's cells, one per column; anything that implements `Display`.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new footer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{Aggregate, Table};
+    ///
+    /// let table = Table::new(
+    ///     vec!["Item", "Qty", "Unit", "Amount"],
+    ///     vec![
+    ///         vec!["Hosting", "12", "15.00", "180.00"],
+    ///         vec!["Support", "1", "200.00", "200.00"],
+    ///     ],
+    /// )
+    /// .with_footer(["Total"])
+    /// .with_footer_span(0, 3)
+    /// .with_aggregate(3, Aggregate::Sum);
+    ///
+    /// let rendered = table.render();
+    /// let lines: Vec<&str> = rendered.lines().map(str::trim_end).collect();
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         "--------- ----- -------- --------",
+    ///         " Item      Qty   Unit     Amount",
+    ///         "--------- ----- -------- --------",
+    ///         " Hosting   12    15.00    180.00",
+    ///         " Support   1     200.00   200.00",
+    ///         "--------- ----- -------- --------",
+    ///         " Total                    380.00",
+    ///         "--------- ----- -------- --------",
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_footer<I, T>(mut self, cells: I) -> Table
+    where
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        self.footer = cells.into_iter().map(|cell| cell.to_string()).collect();
+        self
+    }
+
+    /// Makes a footer cell span several columns, such as a `Total` label across the columns
+    /// left of the amounts.
+    ///
+    /// As with [`Table::with_span`], the columns are widened if the cell does not fit, and the
+    /// footer cells and aggregates it covers are not printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the first column the cell spans.
+    /// * `columns` - The number of columns the cell spans.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new footer span.
+    pub fn with_footer_span(mut self, column: usize, columns: usize) -> Table {
+        self.footer_spans.insert(column, columns);
+        self
+    }
+
+    /// Shows an aggregate of a column's cells, such as its sum or average, in the footer.
+    ///
+    /// The aggregate replaces any footer cell set for that column, and is computed when the table
+    /// is printed, so rows added, sorted or filtered later are taken into account.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The zero-based index of the column to aggregate.
+    /// * `aggregate` - The aggregate to show.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` with the new aggregate.
+    pub fn with_aggregate(mut self, column: usize, aggregate: Aggregate) -> Table {
+        self.aggregates.insert(column, aggregate);
+        self
+    }
+
+    /// Builds the footer row, computing any aggregates.
+    ///
+    /// # Returns
+    ///
+    /// The footer's cells, or `None` if the table has no footer.
+    pub(super) fn footer_row(&self) -> Option<Vec<String>> {
+        if self.footer.is_empty() && self.aggregates.is_empty() {
+            return None;
+        }
+
+        let mut cells = self.footer.clone();
+        cells.resize(self.column_count(), String::new());
+        for (&column, aggregate) in &self.aggregates {
+            if let Some(cell) = cells.get_mut(column) {
+                *cell = aggregate.compute(
+                    self.rows
+                        .iter()
+                        .map(|row| row.get(column).map_or("", String::as_str)),
+                );
+            }
+        }
+        Some(cells)
+    }
+}
@@ -0,0 +1,301 @@
+use super::{Alignment, BorderLine, Overflow, TableStyle, VerticalAlignment};
+use crate::width::{display_width, padding, split_lines, truncate, wrap};
+use std::io::{self, Write};
+
+/// The resolved width, alignment and overflow of every column of a table, and the style it is
+/// drawn with.
+pub(super) struct Layout<'a> {
+    pub(super) widths: Vec<usize>,
+    pub(super) alignments: Vec<Alignment>,
+    pub(super) vertical_alignments: Vec<VerticalAlignment>,
+    pub(super) overflows: Vec<Overflow>,
+    pub(super) style: &'a TableStyle,
+}
+
+impl Layout<'_> {
+    /// Returns the width taken up by everything but the cells' contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The style the table is drawn with.
+    /// * `column_count` - The number of columns.
+    ///
+    /// # Returns
+    ///
+    /// The combined width of the edges, column separators and cell padding.
+    pub(super) fn chrome_width(style: &TableStyle, column_count: usize) -> usize {
+        if column_count == 0 {
+            return 0;
+        }
+        display_width(&style.left)
+            + display_width(&style.right)
+            + display_width(&style.column_separator) * (column_count - 1)
+            + 2 * style.padding * column_count
+    }
+
+    /// Writes a horizontal border line spanning every column, if the style has one.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the line to.
+    /// * `border` - The line to draw, or `None` to draw nothing.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    pub(super) fn write_line(
+        &self,
+        out: &mut impl Write,
+        border: Option<&BorderLine>,
+    ) -> io::Result<()> {
+        match border {
+            Some(border) => writeln!(out, "{}", self.line(border)),
+            None => Ok(()),
+        }
+    }
+
+    /// Creates a horizontal border line spanning every column.
+    ///
+    /// # Arguments
+    ///
+    /// * `border` - The characters of the line.
+    ///
+    /// # Returns
+    ///
+    /// The line as a `String`.
+    pub(super) fn line(&self, border: &BorderLine) -> String {
+        let columns = self
+            .widths
+            .iter()
+            .map(|&width| {
+                border
+                    .fill
+                    .to_string()
+                    .repeat(width + 2 * self.style.padding)
+            })
+            .collect::<Vec<_>>()
+            .join(&border.junction);
+        format!("{}{}{}", border.left, columns, border.right)
+    }
+
+    /// Writes one row of cells, spreading it over several lines if any cell has several lines or
+    /// wraps.
+    ///
+    /// The row is as tall as its tallest cell, and shorter cells are placed at the top, middle or
+    /// bottom of the row according to their column's vertical alignment.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the row to.
+    /// * `cells` - The row's cells; missing cells are printed empty.
+    /// * `style` - Styles a line of a cell, padding included, given the cell's column.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    pub(super) fn write_row(
+        &self,
+        out: &mut impl Write,
+        cells: &[String],
+        style: impl Fn(usize, &str) -> String,
+    ) -> io::Result<()> {
+        self.write_spanned_row(out, cells, |_| 1, style)
+    }
+
+    /// Writes one row of cells, some of which may span several columns.
+    ///
+    /// A spanning cell takes the alignment, vertical alignment and overflow of its first column,
+    /// and the cells it covers are not printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the row to.
+    /// * `cells` - The row's cells; missing cells are printed empty.
+    /// * `span` - Returns the number of columns the cell in a column spans.
+    /// * `style` - Styles a line of a cell, padding included, given the cell's first column.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    pub(super) fn write_spanned_row(
+        &self,
+        out: &mut impl Write,
+        cells: &[String],
+        span: impl Fn(usize) -> usize,
+        style: impl Fn(usize, &str) -> String,
+    ) -> io::Result<()> {
+        // Each slot is a cell's first column and its width, including any columns it covers.
+        let mut slots = Vec::new();
+        let mut column = 0;
+        while column < self.widths.len() {
+            let columns = span(column).clamp(1, self.widths.len() - column);
+            slots.push((column, self.span_width(column, columns)));
+            column += columns;
+        }
+
+        let cell_lines: Vec<Vec<String>> = slots
+            .iter()
+            .map(|&(i, width)| {
+                let cell = cells.get(i).map_or("", String::as_str);
+                fit_cell(cell, width, self.overflows[i])
+            })
+            .collect();
+        let height = cell_lines.iter().map(Vec::len).max().unwrap_or(1);
+
+        let offsets: Vec<usize> = slots
+            .iter()
+            .zip(&cell_lines)
+            .map(|(&(i, _), lines)| {
+                let spare = height - lines.len();
+                match self.vertical_alignments[i] {
+                    VerticalAlignment::Top => 0,
+                    VerticalAlignment::Middle => spare / 2,
+                    VerticalAlignment::Bottom => spare,
+                }
+            })
+            .collect();
+
+        let cell_padding = " ".repeat(self.style.padding);
+        for line_index in 0..height {
+            let line = slots
+                .iter()
+                .zip(&cell_lines)
+                .zip(&offsets)
+                .map(|((&(i, width), lines), &offset)| {
+                    let text = line_index
+                        .checked_sub(offset)
+                        .and_then(|index| lines.get(index))
+                        .map_or("", String::as_str);
+                    let (left, right) = align(text, width, self.alignments[i]);
+                    style(
+                        i,
+                        &format!("{}{}{}{}{}", cell_padding, left, text, right, cell_padding),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(&self.style.column_separator);
+            writeln!(out, "{}{}{}", self.style.left, line, self.style.right)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the width of a cell that spans several columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The index of the first column spanned.
+    /// * `columns` - The number of columns spanned.
+    ///
+    /// # Returns
+    ///
+    /// The widths of the columns, plus the padding and separators between them.
+    fn span_width(&self, first: usize, columns: usize) -> usize {
+        let between = 2 * self.style.padding + display_width(&self.style.column_separator);
+        self.widths[first..first + columns].iter().sum::<usize>() + between * (columns - 1)
+    }
+
+    /// Writes a line of text that spans every column, such as the title of a group of rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the line to.
+    /// * `text` - The text of the line, truncated if it is wider than the table.
+    /// * `style` - Styles the line, padding included.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    pub(super) fn write_span(
+        &self,
+        out: &mut impl Write,
+        text: &str,
+        style: impl Fn(&str) -> String,
+    ) -> io::Result<()> {
+        let width = match self.widths.len() {
+            0 => 0,
+            columns => self.span_width(0, columns),
+        };
+
+        let cell_padding = " ".repeat(self.style.padding);
+        let text = truncate(text, width);
+        let line = format!(
+            "{}{}{}{}",
+            cell_padding,
+            text,
+            padding(&text, width),
+            cell_padding
+        );
+        writeln!(
+            out,
+            "{}{}{}",
+            self.style.left,
+            style(&line),
+            self.style.right
+        )
+    }
+}
+
+/// Fits a cell's contents to the width of its column.
+///
+/// # Arguments
+///
+/// * `text` - The cell's contents.
+/// * `width` - The width of the column.
+/// * `overflow` - What to do if the contents are wider than the column.
+///
+/// # Returns
+///
+/// The lines of the cell, each at most `width` columns wide.
+fn fit_cell(text: &str, width: usize, overflow: Overflow) -> Vec<String> {
+    split_lines(text)
+        .into_iter()
+        .flat_map(|line| {
+            if display_width(&line) <= width {
+                return vec![line];
+            }
+            match overflow {
+                Overflow::Wrap => wrap(&line, width),
+                Overflow::Truncate => vec![truncate(&line, width)],
+            }
+        })
+        .collect()
+}
+
+/// Returns the display width of a cell, which is the width of its widest line.
+///
+/// # Arguments
+///
+/// * `text` - The cell's contents.
+///
+/// # Returns
+///
+/// The width the cell needs to be shown without wrapping.
+pub(super) fn cell_width(text: &str) -> usize {
+    text.split('\n')
+        .map(|line| display_width(line.strip_suffix('\r').unwrap_or(line)))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Computes the padding on either side of a line of a cell.
+///
+/// # Arguments
+///
+/// * `text` - The line, already fitted to the column.
+/// * `width` - The width of the column.
+/// * `alignment` - How the line is aligned within the column.
+///
+/// # Returns
+///
+/// The left and right padding.
+fn align(text: &str, width: usize, alignment: Alignment) -> (String, String) {
+    let fill = padding(text, width);
+    match alignment {
+        Alignment::Left | Alignment::Auto => (String::new(), fill),
+        Alignment::Right => (fill, String::new()),
+        Alignment::Center => {
+            let left = fill.len() / 2;
+            (fill[..left].to_string(), fill[left..].to_string())
+        }
+    }
+}
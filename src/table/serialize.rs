@@ -0,0 +1,143 @@
+use super::Table;
+use crate::error::Error;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+impl Table {
+    /// Builds a table from a slice of serializable structs, one row per struct.
+    ///
+    /// The headers are the field names, in declaration order, and the cells are the field values.
+    /// Nested structs and maps are flattened into `parent.child` columns, sequences are
+    /// JSON-encoded, and `None` values are left empty. Requires the `serde` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The structs (or maps) to list.
+    ///
+    /// # Returns
+    ///
+    /// The `Table`, or `Error::InvalidInput` if an item does not serialize to a struct or map.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::Table;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Region {
+    ///     name: String,
+    ///     zone: char,
+    /// }
+    ///
+    /// #[derive(Serialize)]
+    /// struct Deployment {
+    ///     service: String,
+    ///     replicas: u32,
+    ///     region: Region,
+    ///     tags: Vec<String>,
+    /// }
+    ///
+    /// let deployments = vec![Deployment {
+    ///     service: "api".to_string(),
+    ///     replicas: 3,
+    ///     region: Region {
+    ///         name: "eu-west".to_string(),
+    ///         zone: 'b',
+    ///     },
+    ///     tags: vec!["canary".to_string()],
+    /// }];
+    ///
+    /// let table = Table::from_serialize(&deployments).unwrap();
+    /// assert_eq!(
+    ///     table.headers(),
+    ///     ["service", "replicas", "region.name", "region.zone", "tags"]
+    /// );
+    /// assert_eq!(table.rows()[0], ["api", "3", "eu-west", "b", "[\"canary\"]"]);
+    /// ```
+    pub fn from_serialize<T: Serialize>(items: &[T]) -> Result<Table, Error> {
+        let mut headers: Vec<String> = Vec::new();
+        let mut records = Vec::with_capacity(items.len());
+
+        for item in items {
+            let value = serde_json::to_value(item)
+                .map_err(|err| Error::InvalidInput(format!("cannot serialize row: {}", err)))?;
+            let Value::Object(fields) = value else {
+                return Err(Error::InvalidInput(format!(
+                    "table rows must serialize to structs or maps, got {}",
+                    value
+                )));
+            };
+
+            let mut record = Vec::new();
+            flatten("", fields, &mut record);
+            for (key, _) in &record {
+                if !headers.contains(key) {
+                    headers.push(key.clone());
+                }
+            }
+            records.push(record);
+        }
+
+        let rows = records
+            .into_iter()
+            .map(|record| {
+                headers
+                    .iter()
+                    .map(|header| {
+                        record
+                            .iter()
+                            .find(|(key, _)| key == header)
+                            .map(|(_, cell)| cell.clone())
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Table {
+            headers,
+            rows,
+            ..Default::default()
+        })
+    }
+}
+
+/// Flattens the fields of an object into `(column, cell)` pairs.
+///
+/// # Arguments
+///
+/// * `prefix` - The column name of the enclosing object, or `""` at the top level.
+/// * `fields` - The fields to flatten.
+/// * `record` - The pairs to append to.
+fn flatten(prefix: &str, fields: Map<String, Value>, record: &mut Vec<(String, String)>) {
+    for (name, value) in fields {
+        let column = if prefix.is_empty() {
+            name
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+
+        match value {
+            Value::Object(nested) if !nested.is_empty() => flatten(&column, nested, record),
+            value => record.push((column, cell(value))),
+        }
+    }
+}
+
+/// Formats a JSON value as a table cell.
+///
+/// # Arguments
+///
+/// * `value` - The value to format.
+///
+/// # Returns
+///
+/// Strings as-is, `null` as an empty cell, and anything else as JSON.
+fn cell(value: Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text,
+        value => value.to_string(),
+    }
+}
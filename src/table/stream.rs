@@ -0,0 +1,226 @@
+use super::{default_header_style, paint_cell, Table};
+use crate::color::Painter;
+use crate::style_options::StyleOptions;
+use std::fmt::Display;
+use std::io::{self, stdout, IsTerminal, Write};
+
+/// Settings for printing a table whose rows arrive one at a time.
+#[derive(Debug, Clone)]
+pub struct StreamOptions {
+    /// The number of rows read ahead to size the columns. Later rows that are wider wrap or are
+    /// truncated.
+    pub sample_size: usize,
+    /// The widths of the first columns, used instead of sampling rows.
+    ///
+    /// If there are fewer widths than headers, the remaining columns are sized from a sample of
+    /// rows as usual. Widths beyond the last header are ignored, except in tables without
+    /// headers, where they add columns.
+    pub widths: Option<Vec<usize>>,
+    /// The number of rows per page, with the header printed again at the start of each page.
+    pub page_size: Option<usize>,
+}
+
+/// Returns options that size the columns from the first 100 rows and print a single page.
+///
+/// # Returns
+///
+/// A `StreamOptions` instance with a `sample_size` of 100 and no fixed widths or pages.
+impl Default for StreamOptions {
+    fn default() -> Self {
+        StreamOptions {
+            sample_size: 100,
+            widths: None,
+            page_size: None,
+        }
+    }
+}
+
+impl Table {
+    /// Writes the table to the given writer as its rows arrive, without collecting them first.
+    ///
+    /// The table is used as a template: its headers, column options and style apply, and any rows
+    /// it already has are printed first. Columns are sized from those rows and the first
+    /// `sample_size` rows of `rows`, or set by `widths`, and then each row is written as soon as
+    /// it is read. Groups and footers are not printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the table to.
+    /// * `rows` - The rows to print, each an iterator of cells.
+    /// * `options` - How columns are sized and how often the header is repeated.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::table::{StreamOptions, Table};
+    ///
+    /// let table = Table::new(vec!["Id", "Name"], Vec::<Vec<String>>::new());
+    /// let rows = (1..=3).map(|id| [id.to_string(), format!("user{}", id)]);
+    ///
+    /// let mut out = Vec::new();
+    /// let options = StreamOptions {
+    ///     sample_size: 1,
+    ///     page_size: Some(2),
+    ///     ..Default::default()
+    /// };
+    /// table.write_stream(&mut out, rows, &options).unwrap();
+    ///
+    /// let rendered = String::from_utf8(out).unwrap();
+    /// let lines: Vec<&str> = rendered.lines().map(str::trim_end).collect();
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         "---- -------",
+    ///         " Id   Name",
+    ///         "---- -------",
+    ///         " 1    user1",
+    ///         " 2    user2",
+    ///         "---- -------",
+    ///         " Id   Name",
+    ///         "---- -------",
+    ///         " 3    user3",
+    ///         "---- -------",
+    ///     ]
+    /// );
+    ///
+    /// // Fix the width of the first column; the second is still sized from the rows.
+    /// let mut out = Vec::new();
+    /// let options = StreamOptions {
+    ///     widths: Some(vec![6]),
+    ///     ..Default::default()
+    /// };
+    /// table.write_stream(&mut out, [["7", "user7"]], &options).unwrap();
+    ///
+    /// let rendered = String::from_utf8(out).unwrap();
+    /// assert!(rendered.contains(" Id       Name "));
+    /// assert!(rendered.contains(" 7        user7 "));
+    /// ```
+    pub fn write_stream<I, R, T>(
+        &self,
+        out: &mut impl Write,
+        rows: I,
+        options: &StreamOptions,
+    ) -> io::Result<()>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Display,
+    {
+        let painter = self.color_choice.painter(false);
+        self.write_pages(out, rows, options, &painter, &default_header_style(), false)
+    }
+
+    /// Prints the table to stdout as its rows arrive.
+    ///
+    /// See [`Table::write_stream`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows to print, each an iterator of cells.
+    /// * `options` - How columns are sized and how often the header is repeated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to stdout fails.
+    pub fn print_stream<I, R, T>(&self, rows: I, options: &StreamOptions)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Display,
+    {
+        let is_terminal = stdout().is_terminal();
+        let painter = self.color_choice.painter(is_terminal);
+        self.write_pages(
+            &mut stdout(),
+            rows,
+            options,
+            &painter,
+            &default_header_style(),
+            is_terminal,
+        )
+        .expect("Failed to print table");
+    }
+
+    /// Writes the table's rows followed by the given rows, repeating the header on every page.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to print the table to.
+    /// * `rows` - The rows to print after the table's own.
+    /// * `options` - How columns are sized and how many rows make a page.
+    /// * `painter` - Decides whether and how the table is styled.
+    /// * `fallback_header_style` - The header style to use if the table does not set one.
+    /// * `is_terminal` - Whether the output is a terminal, whose width the table then fits unless
+    ///   it sets a maximum width.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or any encountered I/O error.
+    pub(crate) fn write_pages<I, R, T>(
+        &self,
+        out: &mut impl Write,
+        rows: I,
+        options: &StreamOptions,
+        painter: &Painter,
+        fallback_header_style: &StyleOptions,
+        is_terminal: bool,
+    ) -> io::Result<()>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Display,
+    {
+        let mut rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(|cell| cell.to_string()).collect());
+
+        // Rows are only sampled if some column has no fixed width.
+        let fixed = options.widths.as_deref();
+        let mut sample = self.clone();
+        if fixed.is_none_or(|fixed| fixed.len() < sample.headers.len()) {
+            sample.rows.extend(rows.by_ref().take(options.sample_size));
+        }
+        let layout = match fixed {
+            None => sample.layout(is_terminal),
+            Some(fixed) => {
+                let mut widths = sample.column_widths();
+                if sample.headers.is_empty() {
+                    widths.resize(widths.len().max(fixed.len()), 0);
+                }
+                for (width, &fixed) in widths.iter_mut().zip(fixed) {
+                    *width = fixed;
+                }
+                sample.layout_with(widths)
+            }
+        };
+        let sampled = sample.rows.clone();
+
+        sample.write_header(out, &layout, painter, fallback_header_style)?;
+        for (written, row) in sampled.into_iter().chain(rows).enumerate() {
+            let page_full = options
+                .page_size
+                .is_some_and(|size| written > 0 && written % size.max(1) == 0);
+            if page_full {
+                // Start the next page with the header again, set apart by the header separator.
+                if !sample.headers.is_empty() {
+                    layout.write_line(out, sample.style.header_separator.as_ref())?;
+                    sample.write_header_row(out, &layout, painter, fallback_header_style)?;
+                }
+            } else if written > 0 {
+                layout.write_line(out, sample.style.row_separator.as_ref())?;
+            }
+
+            layout.write_row(out, &row, |column, cell| {
+                paint_cell(painter, &sample.cell_style(written, column), cell)
+            })?;
+            out.flush()?;
+        }
+
+        layout.write_line(out, sample.style.bottom.as_ref())?;
+        out.flush()
+    }
+}
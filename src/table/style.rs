@@ -0,0 +1,260 @@
+/// The characters of a horizontal border line, such as the line under a table's header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorderLine {
+    /// Printed at the start of the line, where it meets the left edge.
+    pub left: String,
+    /// Repeated across the width of each column.
+    pub fill: char,
+    /// Printed between columns, where the line crosses a column separator.
+    pub junction: String,
+    /// Printed at the end of the line, where it meets the right edge.
+    pub right: String,
+}
+
+impl BorderLine {
+    /// Creates a border line from its characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The character where the line meets the left edge.
+    /// * `fill` - The character repeated across each column.
+    /// * `junction` - The character where the line crosses a column separator.
+    /// * `right` - The character where the line meets the right edge.
+    ///
+    /// # Returns
+    ///
+    /// A new `BorderLine`.
+    pub fn new(left: &str, fill: char, junction: &str, right: &str) -> BorderLine {
+        BorderLine {
+            left: left.to_string(),
+            fill,
+            junction: junction.to_string(),
+            right: right.to_string(),
+        }
+    }
+}
+
+/// The borders and separators a table is drawn with.
+///
+/// Each horizontal line is optional, so a style can draw an outer frame, a line under the
+/// header, lines between rows, or none of them. Pick one of the presets or build your own.
+///
+/// # Example
+///
+/// ```rust
+/// use rustic_print::table::{Table, TableStyle};
+///
+/// let table = Table::new(vec!["Name", "Role"], vec![vec!["Ada", "Engineer"]])
+///     .with_style(TableStyle::rounded());
+///
+/// let rendered = table.render();
+/// let lines: Vec<&str> = rendered.lines().collect();
+/// assert_eq!(
+///     lines,
+///     [
+///         "╭──────┬──────────╮",
+///         "│ Name │ Role     │",
+///         "├──────┼──────────┤",
+///         "│ Ada  │ Engineer │",
+///         "╰──────┴──────────╯",
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableStyle {
+    /// The line above the header.
+    pub top: Option<BorderLine>,
+    /// The line between the header and the first row.
+    pub header_separator: Option<BorderLine>,
+    /// The line between consecutive rows.
+    pub row_separator: Option<BorderLine>,
+    /// The line below the last row.
+    pub bottom: Option<BorderLine>,
+    /// Printed at the start of every header and row line.
+    pub left: String,
+    /// Printed between the cells of a line.
+    pub column_separator: String,
+    /// Printed at the end of every header and row line.
+    pub right: String,
+    /// The number of spaces on either side of each cell.
+    pub padding: usize,
+}
+
+impl TableStyle {
+    /// Returns the classic Rustic Print style: dashed lines above and below the header and below
+    /// the last row, with columns separated by spaces.
+    ///
+    /// # Returns
+    ///
+    /// The default `TableStyle`.
+    pub fn classic() -> TableStyle {
+        let line = BorderLine::new("", '-', " ", "");
+        TableStyle {
+            top: Some(line.clone()),
+            header_separator: Some(line.clone()),
+            row_separator: None,
+            bottom: Some(line),
+            left: String::new(),
+            column_separator: " ".to_string(),
+            right: String::new(),
+            padding: 1,
+        }
+    }
+
+    /// Returns a full ASCII grid, with `+` corners, `|` separators and a line between every row.
+    ///
+    /// # Returns
+    ///
+    /// A `TableStyle` that renders the same in any terminal or log file.
+    pub fn ascii_grid() -> TableStyle {
+        let line = BorderLine::new("+", '-', "+", "+");
+        TableStyle {
+            top: Some(line.clone()),
+            header_separator: Some(BorderLine::new("+", '=', "+", "+")),
+            row_separator: Some(line.clone()),
+            bottom: Some(line),
+            ..TableStyle::framed("|")
+        }
+    }
+
+    /// Returns a box drawn with single Unicode box-drawing lines.
+    ///
+    /// # Returns
+    ///
+    /// A `TableStyle` with a `┌─┬─┐` frame.
+    pub fn unicode() -> TableStyle {
+        TableStyle {
+            top: Some(BorderLine::new("┌", '─', "┬", "┐")),
+            header_separator: Some(BorderLine::new("├", '─', "┼", "┤")),
+            row_separator: None,
+            bottom: Some(BorderLine::new("└", '─', "┴", "┘")),
+            ..TableStyle::framed("│")
+        }
+    }
+
+    /// Returns a box drawn with double Unicode box-drawing lines.
+    ///
+    /// # Returns
+    ///
+    /// A `TableStyle` with a `╔═╦═╗` frame.
+    pub fn double() -> TableStyle {
+        TableStyle {
+            top: Some(BorderLine::new("╔", '═', "╦", "╗")),
+            header_separator: Some(BorderLine::new("╠", '═', "╬", "╣")),
+            row_separator: None,
+            bottom: Some(BorderLine::new("╚", '═', "╩", "╝")),
+            ..TableStyle::framed("║")
+        }
+    }
+
+    /// Returns a box drawn with single Unicode lines and rounded corners.
+    ///
+    /// # Returns
+    ///
+    /// A `TableStyle` with a `╭─┬─╮` frame.
+    pub fn rounded() -> TableStyle {
+        TableStyle {
+            top: Some(BorderLine::new("╭", '─', "┬", "╮")),
+            bottom: Some(BorderLine::new("╰", '─', "┴", "╯")),
+            ..TableStyle::unicode()
+        }
+    }
+
+    /// Returns a Markdown pipe table, as understood by GitHub and most Markdown renderers.
+    ///
+    /// # Returns
+    ///
+    /// A `TableStyle` with `|` separators and a `|---|` line under the header.
+    pub fn markdown() -> TableStyle {
+        TableStyle {
+            top: None,
+            header_separator: Some(BorderLine::new("|", '-', "|", "|")),
+            row_separator: None,
+            bottom: None,
+            ..TableStyle::framed("|")
+        }
+    }
+
+    /// Returns a style with no frame and a single Unicode line under the header.
+    ///
+    /// # Returns
+    ///
+    /// A `TableStyle` that keeps the header distinct without boxing the table in.
+    pub fn minimal() -> TableStyle {
+        TableStyle {
+            top: None,
+            header_separator: Some(BorderLine::new("", '─', " ", "")),
+            row_separator: None,
+            bottom: None,
+            left: String::new(),
+            column_separator: " ".to_string(),
+            right: String::new(),
+            padding: 1,
+        }
+    }
+
+    /// Returns a style without any lines, separating columns with spaces alone.
+    ///
+    /// # Returns
+    ///
+    /// A `TableStyle` with no borders.
+    pub fn borderless() -> TableStyle {
+        TableStyle {
+            header_separator: None,
+            ..TableStyle::minimal()
+        }
+    }
+
+    /// Returns the most space-efficient style: no frame, no cell padding, columns separated by
+    /// two spaces and a dashed line under the header.
+    ///
+    /// # Returns
+    ///
+    /// A `TableStyle` suited to narrow terminals and plain logs.
+    pub fn compact() -> TableStyle {
+        TableStyle {
+            top: None,
+            header_separator: Some(BorderLine::new("", '-', "  ", "")),
+            row_separator: None,
+            bottom: None,
+            left: String::new(),
+            column_separator: "  ".to_string(),
+            right: String::new(),
+            padding: 0,
+        }
+    }
+
+    /// Returns a style without horizontal lines whose edges and column separators are all the
+    /// given string.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertical` - The vertical border, such as `"|"`.
+    ///
+    /// # Returns
+    ///
+    /// A `TableStyle` that the framed presets fill in.
+    fn framed(vertical: &str) -> TableStyle {
+        TableStyle {
+            top: None,
+            header_separator: None,
+            row_separator: None,
+            bottom: None,
+            left: vertical.to_string(),
+            column_separator: vertical.to_string(),
+            right: vertical.to_string(),
+            padding: 1,
+        }
+    }
+}
+
+/// Returns the classic table style.
+///
+/// # Returns
+///
+/// The same `TableStyle` as `TableStyle::classic()`.
+impl Default for TableStyle {
+    fn default() -> Self {
+        TableStyle::classic()
+    }
+}
@@ -0,0 +1,100 @@
+use super::{ColumnOptions, Table};
+
+/// A type whose values can be listed as the rows of a [`Table`].
+///
+/// With the `derive` feature, implement it with `#[derive(Tabled)]` and describe the columns with
+/// `#[tabled(...)]` field attributes:
+///
+/// - `rename = "Header"` sets the column header (the field name by default).
+/// - `skip` leaves the field out of the table.
+/// - `align = "right"` aligns the column `left`, `right`, `center` or `auto`.
+/// - `format = "path::to::function"` formats the field with a function taking a reference to it
+///   and returning anything that implements `Display`; otherwise the field itself must
+///   implement `Display`.
+/// - `order = 0` moves the column to the given position. Other columns fill the positions left
+///   over, in declaration order. Two columns can't ask for the same position.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use rustic_print::table::{Table, Tabled};
+///
+/// fn kilobytes(bytes: &u64) -> String {
+///     format!("{} KB", bytes / 1024)
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Artifact {
+///     #[tabled(rename = "File")]
+///     name: String,
+///     #[tabled(format = "kilobytes", align = "right")]
+///     size: u64,
+///     #[tabled(skip)]
+///     checksum: String,
+///     #[tabled(order = 0)]
+///     target: &'static str,
+/// }
+///
+/// let artifacts = [Artifact {
+///     name: "app.tar.gz".to_string(),
+///     size: 2_048_000,
+///     checksum: "9f86d08".to_string(),
+///     target: "linux",
+/// }];
+///
+/// let table = Table::from_tabled(&artifacts);
+/// assert_eq!(table.headers(), ["target", "File", "size"]);
+/// assert_eq!(table.rows()[0], ["linux", "app.tar.gz", "2000 KB"]);
+///
+/// // Columns without an order fill the positions left over, in declaration order.
+/// #[derive(Tabled)]
+/// struct Release {
+///     #[tabled(order = 2)]
+///     notes: &'static str,
+///     version: &'static str,
+///     date: &'static str,
+/// }
+///
+/// let releases = [Release { notes: "Tables", version: "0.3.0", date: "2026-10-17" }];
+/// assert_eq!(Table::from_tabled(&releases).headers(), ["version", "date", "notes"]);
+/// # }
+/// ```
+pub trait Tabled {
+    /// Returns the header of each column, in display order.
+    fn headers() -> Vec<String>;
+
+    /// Returns the options of each column, in display order.
+    ///
+    /// Columns without an entry use the default options.
+    fn column_options() -> Vec<ColumnOptions> {
+        Vec::new()
+    }
+
+    /// Returns the cells of this value's row, in the same order as the headers.
+    fn row(&self) -> Vec<String>;
+}
+
+impl Table {
+    /// Builds a table from values of a type that implements [`Tabled`], one row per value.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The values to list.
+    ///
+    /// # Returns
+    ///
+    /// A `Table` with the type's headers and column options.
+    pub fn from_tabled<T: Tabled>(items: &[T]) -> Table {
+        let mut table = Table {
+            headers: T::headers(),
+            rows: items.iter().map(Tabled::row).collect(),
+            ..Default::default()
+        };
+        for (index, options) in T::column_options().into_iter().enumerate() {
+            table = table.with_column(index, options);
+        }
+        table
+    }
+}
@@ -0,0 +1,444 @@
+use crate::block_options::BlockOptions;
+use crate::style_options::StyleOptions;
+use crossterm::style::{Attribute, Color};
+
+mod config;
+
+/// Options for headings rendered as a message underlined with a repeated character.
+#[derive(Debug, Clone)]
+pub struct UnderlineOptions {
+    pub underline_char: char,
+    pub style: Option<StyleOptions>,
+}
+
+/// Styles used by the interactive prompts (`confirm`, `ask` and `choice`).
+///
+/// `question` and `default_value` style `confirm`, and the question of `choice`. `ask` has its
+/// own `input_question` and `input_default_value`, and `choice` its own `choice_default_value`,
+/// so each prompt can keep a look of its own.
+#[derive(Debug, Clone)]
+pub struct PromptTheme {
+    pub question: StyleOptions,
+    pub default_value: StyleOptions,
+    pub input_question: StyleOptions,
+    pub input_default_value: StyleOptions,
+    pub choice_default_value: StyleOptions,
+    pub choice_index: StyleOptions,
+    pub suggestion: StyleOptions,
+    pub validation_error: StyleOptions,
+}
+
+/// Styles used when rendering tables.
+#[derive(Debug, Clone)]
+pub struct TableTheme {
+    pub header: StyleOptions,
+}
+
+/// The complete set of styles used by a `RusticPrint` instance.
+///
+/// Each message kind has its own `BlockOptions`, so a whole tool can be re-skinned by building
+/// its `RusticPrint` with a different theme.
+///
+/// # Example
+///
+/// ```rust
+/// use rustic_print::theme::Theme;
+/// use rustic_print::RusticPrint;
+///
+/// let mut theme = Theme::high_contrast();
+/// theme.success.block_type = Some("DONE".to_string());
+///
+/// let printer = RusticPrint::builder().theme(theme).build();
+/// printer.success("Deployment finished.");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub success: BlockOptions,
+    pub caution: BlockOptions,
+    pub error: BlockOptions,
+    pub comment: BlockOptions,
+    pub warning: BlockOptions,
+    pub info: BlockOptions,
+    pub note: BlockOptions,
+    pub title: UnderlineOptions,
+    pub section: UnderlineOptions,
+    pub prompt: PromptTheme,
+    pub table: TableTheme,
+}
+
+impl Theme {
+    /// Returns a theme without any colors, relying on labels and prefixes alone.
+    ///
+    /// # Returns
+    ///
+    /// A `Theme` with the default labels, prefixes and padding, but no colors. Table headers are
+    /// still bold.
+    pub fn monochrome() -> Theme {
+        let plain = StyleOptions::default();
+        let default = Theme::default();
+
+        Theme {
+            success: BlockOptions {
+                style: None,
+                ..default.success
+            },
+            caution: BlockOptions {
+                style: None,
+                ..default.caution
+            },
+            error: BlockOptions {
+                style: None,
+                ..default.error
+            },
+            comment: BlockOptions {
+                style: None,
+                ..default.comment
+            },
+            warning: BlockOptions {
+                style: None,
+                ..default.warning
+            },
+            info: BlockOptions {
+                style: None,
+                ..default.info
+            },
+            note: BlockOptions {
+                style: None,
+                ..default.note
+            },
+            title: UnderlineOptions {
+                style: None,
+                ..default.title
+            },
+            section: UnderlineOptions {
+                style: None,
+                ..default.section
+            },
+            prompt: PromptTheme {
+                question: plain.clone(),
+                default_value: plain.clone(),
+                input_question: plain.clone(),
+                input_default_value: plain.clone(),
+                choice_default_value: plain.clone(),
+                choice_index: plain.clone(),
+                suggestion: plain.clone(),
+                validation_error: plain.clone(),
+            },
+            table: TableTheme {
+                header: plain.with_attribute(Attribute::Bold),
+            },
+        }
+    }
+
+    /// Returns a theme using bright, strongly contrasting colors.
+    ///
+    /// # Returns
+    ///
+    /// A `Theme` suited to low-contrast displays and projectors.
+    pub fn high_contrast() -> Theme {
+        Theme::from_palette(Palette {
+            success: (Color::Black, Color::Green),
+            failure: (Color::White, Color::Red),
+            warning: (Color::Black, Color::Yellow),
+            info: Color::Cyan,
+            note: Color::Yellow,
+            comment: None,
+            heading: Color::White,
+            prompt: Color::Cyan,
+            default_value: Color::Yellow,
+            suggestion: (Color::Black, Color::White),
+            validation_error: Color::Red,
+        })
+    }
+
+    /// Returns a theme based on the Solarized color palette.
+    ///
+    /// The palette uses true colors, so it is best suited to terminals that support them.
+    ///
+    /// # Returns
+    ///
+    /// A `Theme` using Solarized colors.
+    pub fn solarized() -> Theme {
+        let base03 = Color::Rgb { r: 0, g: 43, b: 54 };
+        let base1 = Color::Rgb {
+            r: 147,
+            g: 161,
+            b: 161,
+        };
+        let yellow = Color::Rgb {
+            r: 181,
+            g: 137,
+            b: 0,
+        };
+        let red = Color::Rgb {
+            r: 220,
+            g: 50,
+            b: 47,
+        };
+        let blue = Color::Rgb {
+            r: 38,
+            g: 139,
+            b: 210,
+        };
+        let cyan = Color::Rgb {
+            r: 42,
+            g: 161,
+            b: 152,
+        };
+        let green = Color::Rgb {
+            r: 133,
+            g: 153,
+            b: 0,
+        };
+
+        Theme::from_palette(Palette {
+            success: (base03, green),
+            failure: (base03, red),
+            warning: (base03, yellow),
+            info: blue,
+            note: yellow,
+            comment: Some(base1),
+            heading: cyan,
+            prompt: blue,
+            default_value: yellow,
+            suggestion: (base03, base1),
+            validation_error: red,
+        })
+    }
+
+    /// Builds a theme with the default labels, prefixes and padding from a set of colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `palette` - The colors to use for each kind of output.
+    ///
+    /// # Returns
+    ///
+    /// A `Theme` using the given colors.
+    fn from_palette(palette: Palette) -> Theme {
+        let default = Theme::default();
+        let block_style = |(foreground, background): (Color, Color)| {
+            Some(StyleOptions {
+                foreground: Some(foreground),
+                background: Some(background),
+                ..Default::default()
+            })
+        };
+        let foreground = |color: Color| StyleOptions {
+            foreground: Some(color),
+            ..Default::default()
+        };
+
+        Theme {
+            success: BlockOptions {
+                style: block_style(palette.success),
+                ..default.success
+            },
+            caution: BlockOptions {
+                style: block_style(palette.failure),
+                ..default.caution
+            },
+            error: BlockOptions {
+                style: block_style(palette.failure),
+                ..default.error
+            },
+            comment: BlockOptions {
+                style: palette.comment.map(foreground),
+                ..default.comment
+            },
+            warning: BlockOptions {
+                style: block_style(palette.warning),
+                ..default.warning
+            },
+            info: BlockOptions {
+                style: Some(foreground(palette.info)),
+                ..default.info
+            },
+            note: BlockOptions {
+                style: Some(foreground(palette.note)),
+                ..default.note
+            },
+            title: UnderlineOptions {
+                style: Some(foreground(palette.heading)),
+                ..default.title
+            },
+            section: UnderlineOptions {
+                style: Some(foreground(palette.heading)),
+                ..default.section
+            },
+            prompt: PromptTheme {
+                question: foreground(palette.prompt),
+                default_value: foreground(palette.default_value),
+                input_question: foreground(palette.prompt),
+                input_default_value: foreground(palette.default_value),
+                choice_default_value: foreground(palette.default_value),
+                choice_index: foreground(palette.prompt),
+                suggestion: StyleOptions {
+                    foreground: Some(palette.suggestion.0),
+                    background: Some(palette.suggestion.1),
+                    ..Default::default()
+                },
+                validation_error: foreground(palette.validation_error),
+            },
+            table: TableTheme {
+                header: foreground(palette.heading).with_attribute(Attribute::Bold),
+            },
+        }
+    }
+}
+
+/// Returns the default theme, matching the classic Rustic Print look.
+///
+/// # Returns
+///
+/// A `Theme` instance with:
+/// - `success`: black on dark green, labeled "OK"
+/// - `caution`: grey on dark red, labeled "CAUTION" with a `" ! "` prefix
+/// - `error`: grey on dark red, labeled "ERROR"
+/// - `comment`: unstyled with a `" // "` prefix
+/// - `warning`: black on dark yellow, labeled "WARNING"
+/// - `info`: green, labeled "INFO"
+/// - `note`: dark yellow, labeled "NOTE" with a `" ! "` prefix and no padding
+/// - `title` and `section`: dark green, underlined with `=` and `-`
+/// - `prompt`: green `confirm` questions with yellow defaults, dark green `ask` questions and
+///   defaults, and green `choice` questions, defaults and indexes
+/// - `table`: bold green headers
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            success: BlockOptions {
+                style: Some(StyleOptions {
+                    foreground: Some(Color::Black),
+                    background: Some(Color::DarkGreen),
+                    ..Default::default()
+                }),
+                block_type: Some("OK".to_string()),
+                padding: true,
+                ..Default::default()
+            },
+            caution: BlockOptions {
+                style: Some(StyleOptions {
+                    foreground: Some(Color::Grey),
+                    background: Some(Color::DarkRed),
+                    ..Default::default()
+                }),
+                block_type: Some("CAUTION".to_string()),
+                prefix: " ! ".to_string(),
+                padding: true,
+            },
+            error: BlockOptions {
+                style: Some(StyleOptions {
+                    foreground: Some(Color::Grey),
+                    background: Some(Color::DarkRed),
+                    ..Default::default()
+                }),
+                block_type: Some("ERROR".to_string()),
+                prefix: " ".to_string(),
+                padding: true,
+            },
+            comment: BlockOptions {
+                prefix: " // ".to_string(),
+                ..Default::default()
+            },
+            warning: BlockOptions {
+                style: Some(StyleOptions {
+                    foreground: Some(Color::Black),
+                    background: Some(Color::DarkYellow),
+                    ..Default::default()
+                }),
+                block_type: Some("WARNING".to_string()),
+                padding: true,
+                ..Default::default()
+            },
+            info: BlockOptions {
+                style: Some(StyleOptions {
+                    foreground: Some(Color::Green),
+                    ..Default::default()
+                }),
+                block_type: Some("INFO".to_string()),
+                padding: true,
+                ..Default::default()
+            },
+            note: BlockOptions {
+                style: Some(StyleOptions {
+                    foreground: Some(Color::DarkYellow),
+                    ..Default::default()
+                }),
+                block_type: Some("NOTE".to_string()),
+                prefix: " ! ".to_string(),
+                ..Default::default()
+            },
+            title: UnderlineOptions {
+                underline_char: '=',
+                style: Some(StyleOptions {
+                    foreground: Some(Color::DarkGreen),
+                    ..Default::default()
+                }),
+            },
+            section: UnderlineOptions {
+                underline_char: '-',
+                style: Some(StyleOptions {
+                    foreground: Some(Color::DarkGreen),
+                    ..Default::default()
+                }),
+            },
+            prompt: PromptTheme {
+                question: StyleOptions {
+                    foreground: Some(Color::Green),
+                    ..Default::default()
+                },
+                default_value: StyleOptions {
+                    foreground: Some(Color::Yellow),
+                    ..Default::default()
+                },
+                input_question: StyleOptions {
+                    foreground: Some(Color::DarkGreen),
+                    ..Default::default()
+                },
+                input_default_value: StyleOptions {
+                    foreground: Some(Color::DarkGreen),
+                    ..Default::default()
+                },
+                choice_default_value: StyleOptions {
+                    foreground: Some(Color::Green),
+                    ..Default::default()
+                },
+                choice_index: StyleOptions {
+                    foreground: Some(Color::Green),
+                    ..Default::default()
+                },
+                suggestion: StyleOptions {
+                    foreground: Some(Color::White),
+                    background: Some(Color::Grey),
+                    ..Default::default()
+                },
+                validation_error: StyleOptions {
+                    foreground: Some(Color::Red),
+                    ..Default::default()
+                },
+            },
+            table: TableTheme {
+                header: StyleOptions {
+                    foreground: Some(Color::Green),
+                    ..Default::default()
+                }
+                .with_attribute(Attribute::Bold),
+            },
+        }
+    }
+}
+
+/// The colors a built-in theme is derived from.
+struct Palette {
+    success: (Color, Color),
+    failure: (Color, Color),
+    warning: (Color, Color),
+    info: Color,
+    note: Color,
+    comment: Option<Color>,
+    heading: Color,
+    prompt: Color,
+    default_value: Color,
+    suggestion: (Color, Color),
+    validation_error: Color,
+}
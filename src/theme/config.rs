@@ -0,0 +1,604 @@
+use super::Theme;
+use crate::error::Error;
+use crate::style_options::{parse_attributes, parse_color, StyleOptions};
+use std::env;
+#[cfg(any(feature = "toml", feature = "json"))]
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// The environment variable naming a built-in theme or a theme file to load.
+const THEME_VAR: &str = "RUSTIC_PRINT_THEME";
+
+/// The prefix of the environment variables that override individual theme colors.
+const OVERRIDE_PREFIX: &str = "RUSTIC_PRINT_";
+
+impl Theme {
+    /// Returns one of the built-in themes by name.
+    ///
+    /// Recognized names are `default`, `monochrome`, `high-contrast` and `solarized`. Names are
+    /// case-insensitive, and `_` may be used instead of `-`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the built-in theme.
+    ///
+    /// # Returns
+    ///
+    /// The matching `Theme`, or `None` if no built-in theme has that name.
+    pub fn named(name: &str) -> Option<Theme> {
+        match name.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+            "default" => Some(Theme::default()),
+            "monochrome" => Some(Theme::monochrome()),
+            "high-contrast" => Some(Theme::high_contrast()),
+            "solarized" => Some(Theme::solarized()),
+            _ => None,
+        }
+    }
+
+    /// Loads a theme from a TOML or JSON file, chosen by the file's extension.
+    ///
+    /// Loading TOML files requires the `toml` feature, and JSON files the `json` feature. Every
+    /// entry in the file is optional and is applied on top of the `base` theme (the default
+    /// theme unless the file names another built-in one):
+    ///
+    /// ```toml
+    /// base = "monochrome"
+    ///
+    /// [error]
+    /// foreground = "white"
+    /// background = "#dc322f"
+    /// attributes = "bold"
+    /// block_type = "FAILED"
+    /// prefix = " ! "
+    /// padding = true
+    ///
+    /// [title]
+    /// foreground = "cyan"
+    /// underline_char = "~"
+    ///
+    /// [prompt.question]
+    /// foreground = "blue"
+    ///
+    /// [table.header]
+    /// foreground = "208"
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the theme file.
+    ///
+    /// # Returns
+    ///
+    /// The loaded `Theme`, or an error if the file cannot be read or parsed.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Theme, Error> {
+        let path = path.as_ref();
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "toml")]
+            Some("toml") => Theme::from_toml_str(&fs::read_to_string(path)?),
+            #[cfg(not(feature = "toml"))]
+            Some("toml") => Err(Error::InvalidInput(
+                "loading TOML themes requires the `toml` feature".to_string(),
+            )),
+            #[cfg(feature = "json")]
+            Some("json") => Theme::from_json_str(&fs::read_to_string(path)?),
+            #[cfg(not(feature = "json"))]
+            Some("json") => Err(Error::InvalidInput(
+                "loading JSON themes requires the `json` feature".to_string(),
+            )),
+            _ => Err(Error::InvalidInput(format!(
+                "unsupported theme file: {} (expected a .toml or .json file)",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Parses a theme from a TOML string.
+    ///
+    /// See [`Theme::from_file`] for the file format.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The TOML source of the theme.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Theme`, or `Error::InvalidInput` if the TOML is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crossterm::style::Color;
+    /// use rustic_print::theme::Theme;
+    ///
+    /// let theme = Theme::from_toml_str(
+    ///     r#"
+    ///     [warning]
+    ///     background = "dark_magenta"
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let style = theme.warning.style.unwrap();
+    /// assert_eq!(style.background, Some(Color::DarkMagenta));
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(contents: &str) -> Result<Theme, Error> {
+        toml::from_str::<file::ThemeFile>(contents)
+            .map_err(|err| Error::InvalidInput(format!("invalid TOML theme: {}", err)))?
+            .into_theme()
+    }
+
+    /// Parses a theme from a JSON string.
+    ///
+    /// The JSON document has the same structure as the TOML format described in
+    /// [`Theme::from_file`].
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The JSON source of the theme.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Theme`, or `Error::InvalidInput` if the JSON is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crossterm::style::Color;
+    /// use rustic_print::theme::Theme;
+    ///
+    /// let theme =
+    ///     Theme::from_json_str(r##"{ "table": { "header": { "foreground": "#b58900" } } }"##)
+    ///         .unwrap();
+    ///
+    /// assert_eq!(
+    ///     theme.table.header.foreground,
+    ///     Some(Color::Rgb { r: 181, g: 137, b: 0 })
+    /// );
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn from_json_str(contents: &str) -> Result<Theme, Error> {
+        serde_json::from_str::<file::ThemeFile>(contents)
+            .map_err(|err| Error::InvalidInput(format!("invalid JSON theme: {}", err)))?
+            .into_theme()
+    }
+
+    /// Builds a theme from the environment.
+    ///
+    /// `RUSTIC_PRINT_THEME` may name a built-in theme (see [`Theme::named`]) or a theme file (see
+    /// [`Theme::from_file`]); if it is unset, the default theme is used. Individual colors are
+    /// then overridden as described in [`Theme::apply_env_overrides`].
+    ///
+    /// `RusticPrint::new()` and `RusticPrint::builder()` already start from this theme, skipping
+    /// anything invalid. Call `from_env` to report those problems instead.
+    ///
+    /// # Returns
+    ///
+    /// The resulting `Theme`, or the first error if the theme file or any override is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_print::theme::Theme;
+    /// use rustic_print::RusticPrint;
+    ///
+    /// let theme = Theme::from_env().unwrap_or_default();
+    /// let printer = RusticPrint::builder().theme(theme).build();
+    /// printer.info("Styled the way the environment asked.");
+    /// ```
+    pub fn from_env() -> Result<Theme, Error> {
+        let mut errors = Vec::new();
+        let theme = Theme::load_env(&mut errors);
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(theme),
+        }
+    }
+
+    /// Returns the theme from the environment, read once per process.
+    ///
+    /// Unlike [`Theme::from_env`], problems are skipped rather than reported: a theme file that
+    /// can't be loaded leaves the default theme, and an invalid override leaves its entry as it
+    /// was, while every valid override still applies.
+    ///
+    /// # Returns
+    ///
+    /// A copy of the environment's `Theme`.
+    pub(crate) fn from_env_cached() -> Theme {
+        static THEME: OnceLock<Theme> = OnceLock::new();
+        THEME
+            .get_or_init(|| Theme::load_env(&mut Vec::new()))
+            .clone()
+    }
+
+    /// Builds a theme from the environment, applying everything that is valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `errors` - Collects a problem for the theme file and for each invalid override.
+    ///
+    /// # Returns
+    ///
+    /// The resulting `Theme`.
+    fn load_env(errors: &mut Vec<Error>) -> Theme {
+        let mut theme = match env::var(THEME_VAR) {
+            Ok(value) if !value.trim().is_empty() => match Theme::named(&value) {
+                Some(theme) => theme,
+                None => Theme::from_file(value.trim()).unwrap_or_else(|error| {
+                    errors.push(error);
+                    Theme::default()
+                }),
+            },
+            _ => Theme::default(),
+        };
+
+        theme.override_from_env(errors);
+        theme
+    }
+
+    /// Overrides individual colors from `RUSTIC_PRINT_<ENTRY>_FG` and `RUSTIC_PRINT_<ENTRY>_BG`
+    /// environment variables, and text attributes from `RUSTIC_PRINT_<ENTRY>_ATTRS`.
+    ///
+    /// `<ENTRY>` is one of `SUCCESS`, `CAUTION`, `ERROR`, `COMMENT`, `WARNING`, `INFO`, `NOTE`,
+    /// `TITLE`, `SECTION`, `PROMPT_QUESTION`, `PROMPT_DEFAULT_VALUE`, `PROMPT_INPUT_QUESTION`,
+    /// `PROMPT_INPUT_DEFAULT_VALUE`, `PROMPT_CHOICE_DEFAULT_VALUE`, `PROMPT_CHOICE_INDEX`,
+    /// `PROMPT_SUGGESTION`, `PROMPT_VALIDATION_ERROR` or `TABLE_HEADER`. Values are parsed with
+    /// [`parse_color`], so `RUSTIC_PRINT_ERROR_BG=red` and `RUSTIC_PRINT_TITLE_FG=#2aa198` both work.
+    /// Attributes are parsed with [`parse_attributes`], e.g. `RUSTIC_PRINT_COMMENT_ATTRS=dim`.
+    /// Every valid override is applied, even if another one is invalid.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or `Error::InvalidInput` naming the first variable whose value is not a valid
+    /// color or attribute list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crossterm::style::Color;
+    /// use rustic_print::theme::Theme;
+    ///
+    /// std::env::set_var("RUSTIC_PRINT_ERROR_BG", "red");
+    ///
+    /// let mut theme = Theme::default();
+    /// theme.apply_env_overrides().unwrap();
+    /// assert_eq!(theme.error.style.unwrap().background, Some(Color::Red));
+    ///
+    /// // An invalid override is reported, but the valid ones still apply.
+    /// std::env::set_var("RUSTIC_PRINT_WARNING_FG", "not-a-color");
+    ///
+    /// let mut theme = Theme::default();
+    /// assert!(theme.apply_env_overrides().is_err());
+    /// assert_eq!(theme.error.style.unwrap().background, Some(Color::Red));
+    /// ```
+    pub fn apply_env_overrides(&mut self) -> Result<(), Error> {
+        let mut errors = Vec::new();
+        self.override_from_env(&mut errors);
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Applies every valid `RUSTIC_PRINT_<ENTRY>_*` override, skipping invalid ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `errors` - Collects a problem for each variable that is not a valid color or attribute
+    ///   list.
+    fn override_from_env(&mut self, errors: &mut Vec<Error>) {
+        let optional_styles = [
+            ("SUCCESS", &mut self.success.style),
+            ("CAUTION", &mut self.caution.style),
+            ("ERROR", &mut self.error.style),
+            ("COMMENT", &mut self.comment.style),
+            ("WARNING", &mut self.warning.style),
+            ("INFO", &mut self.info.style),
+            ("NOTE", &mut self.note.style),
+            ("TITLE", &mut self.title.style),
+            ("SECTION", &mut self.section.style),
+        ];
+        for (entry, style) in optional_styles {
+            let mut overridden = style.clone().unwrap_or_default();
+            if override_style(entry, &mut overridden, errors) {
+                *style = Some(overridden);
+            }
+        }
+
+        let styles = [
+            ("PROMPT_QUESTION", &mut self.prompt.question),
+            ("PROMPT_DEFAULT_VALUE", &mut self.prompt.default_value),
+            ("PROMPT_INPUT_QUESTION", &mut self.prompt.input_question),
+            (
+                "PROMPT_INPUT_DEFAULT_VALUE",
+                &mut self.prompt.input_default_value,
+            ),
+            (
+                "PROMPT_CHOICE_DEFAULT_VALUE",
+                &mut self.prompt.choice_default_value,
+            ),
+            ("PROMPT_CHOICE_INDEX", &mut self.prompt.choice_index),
+            ("PROMPT_SUGGESTION", &mut self.prompt.suggestion),
+            ("PROMPT_VALIDATION_ERROR", &mut self.prompt.validation_error),
+            ("TABLE_HEADER", &mut self.table.header),
+        ];
+        for (entry, style) in styles {
+            override_style(entry, style, errors);
+        }
+    }
+}
+
+/// Applies the `_FG`, `_BG` and `_ATTRS` environment overrides of a single theme entry.
+///
+/// # Arguments
+///
+/// * `entry` - The entry's name within the environment variable, e.g. `ERROR`.
+/// * `style` - The style to update.
+/// * `errors` - Collects `Error::InvalidInput` for each value that is not a valid color or
+///   attribute list; those values are skipped.
+///
+/// # Returns
+///
+/// Whether any override was applied.
+fn override_style(entry: &str, style: &mut StyleOptions, errors: &mut Vec<Error>) -> bool {
+    let mut overridden = false;
+
+    for (suffix, slot) in [("FG", &mut style.foreground), ("BG", &mut style.background)] {
+        let var = format!("{}{}_{}", OVERRIDE_PREFIX, entry, suffix);
+        if let Ok(value) = env::var(&var) {
+            match parse_color(&value) {
+                Ok(color) => {
+                    *slot = Some(color);
+                    overridden = true;
+                }
+                Err(_) => errors.push(Error::InvalidInput(format!(
+                    "{}: unrecognized color: {:?}",
+                    var, value
+                ))),
+            }
+        }
+    }
+
+    let var = format!("{}{}_ATTRS", OVERRIDE_PREFIX, entry);
+    if let Ok(value) = env::var(&var) {
+        match parse_attributes(&value) {
+            Ok(attributes) => {
+                style.attributes = attributes;
+                overridden = true;
+            }
+            Err(_) => errors.push(Error::InvalidInput(format!(
+                "{}: unrecognized text attributes: {:?}",
+                var, value
+            ))),
+        }
+    }
+
+    overridden
+}
+
+#[cfg(any(feature = "toml", feature = "json"))]
+mod file {
+    use crate::block_options::BlockOptions;
+    use crate::error::Error;
+    use crate::style_options::{parse_attributes, parse_color, StyleOptions};
+    use crate::theme::{Theme, UnderlineOptions};
+    use serde::Deserialize;
+
+    /// The on-disk representation of a theme, where every entry is optional.
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub(super) struct ThemeFile {
+        base: Option<String>,
+        success: Option<BlockEntry>,
+        caution: Option<BlockEntry>,
+        error: Option<BlockEntry>,
+        comment: Option<BlockEntry>,
+        warning: Option<BlockEntry>,
+        info: Option<BlockEntry>,
+        note: Option<BlockEntry>,
+        title: Option<UnderlineEntry>,
+        section: Option<UnderlineEntry>,
+        prompt: Option<PromptEntry>,
+        table: Option<TableEntry>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct StyleEntry {
+        foreground: Option<String>,
+        background: Option<String>,
+        attributes: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct BlockEntry {
+        foreground: Option<String>,
+        background: Option<String>,
+        attributes: Option<String>,
+        block_type: Option<String>,
+        prefix: Option<String>,
+        padding: Option<bool>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct UnderlineEntry {
+        foreground: Option<String>,
+        background: Option<String>,
+        attributes: Option<String>,
+        underline_char: Option<char>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct PromptEntry {
+        question: Option<StyleEntry>,
+        default_value: Option<StyleEntry>,
+        input_question: Option<StyleEntry>,
+        input_default_value: Option<StyleEntry>,
+        choice_default_value: Option<StyleEntry>,
+        choice_index: Option<StyleEntry>,
+        suggestion: Option<StyleEntry>,
+        validation_error: Option<StyleEntry>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct TableEntry {
+        header: Option<StyleEntry>,
+    }
+
+    impl ThemeFile {
+        /// Applies every entry of the file on top of its base theme.
+        ///
+        /// # Returns
+        ///
+        /// The resulting `Theme`, or `Error::InvalidInput` for an unknown base or invalid color.
+        pub(super) fn into_theme(self) -> Result<Theme, Error> {
+            let mut theme = match self.base {
+                Some(base) => Theme::named(&base).ok_or_else(|| {
+                    Error::InvalidInput(format!("unknown base theme: {:?}", base))
+                })?,
+                None => Theme::default(),
+            };
+
+            let blocks = [
+                (self.success, &mut theme.success),
+                (self.caution, &mut theme.caution),
+                (self.error, &mut theme.error),
+                (self.comment, &mut theme.comment),
+                (self.warning, &mut theme.warning),
+                (self.info, &mut theme.info),
+                (self.note, &mut theme.note),
+            ];
+            for (entry, options) in blocks {
+                if let Some(entry) = entry {
+                    entry.apply(options)?;
+                }
+            }
+
+            for (entry, options) in [
+                (self.title, &mut theme.title),
+                (self.section, &mut theme.section),
+            ] {
+                if let Some(entry) = entry {
+                    entry.apply(options)?;
+                }
+            }
+
+            if let Some(prompt) = self.prompt {
+                let styles = [
+                    (prompt.question, &mut theme.prompt.question),
+                    (prompt.default_value, &mut theme.prompt.default_value),
+                    (prompt.input_question, &mut theme.prompt.input_question),
+                    (
+                        prompt.input_default_value,
+                        &mut theme.prompt.input_default_value,
+                    ),
+                    (
+                        prompt.choice_default_value,
+                        &mut theme.prompt.choice_default_value,
+                    ),
+                    (prompt.choice_index, &mut theme.prompt.choice_index),
+                    (prompt.suggestion, &mut theme.prompt.suggestion),
+                    (prompt.validation_error, &mut theme.prompt.validation_error),
+                ];
+                for (entry, style) in styles {
+                    if let Some(entry) = entry {
+                        apply_style(style, entry.foreground, entry.background, entry.attributes)?;
+                    }
+                }
+            }
+
+            if let Some(StyleEntry {
+                foreground,
+                background,
+                attributes,
+            }) = self.table.and_then(|table| table.header)
+            {
+                apply_style(&mut theme.table.header, foreground, background, attributes)?;
+            }
+
+            Ok(theme)
+        }
+    }
+
+    impl BlockEntry {
+        /// Applies this entry to a message kind's block options.
+        fn apply(self, options: &mut BlockOptions) -> Result<(), Error> {
+            apply_optional_style(
+                &mut options.style,
+                self.foreground,
+                self.background,
+                self.attributes,
+            )?;
+            if let Some(block_type) = self.block_type {
+                options.block_type = Some(block_type).filter(|label| !label.is_empty());
+            }
+            if let Some(prefix) = self.prefix {
+                options.prefix = prefix;
+            }
+            if let Some(padding) = self.padding {
+                options.padding = padding;
+            }
+            Ok(())
+        }
+    }
+
+    impl UnderlineEntry {
+        /// Applies this entry to a heading's underline options.
+        fn apply(self, options: &mut UnderlineOptions) -> Result<(), Error> {
+            apply_optional_style(
+                &mut options.style,
+                self.foreground,
+                self.background,
+                self.attributes,
+            )?;
+            if let Some(underline_char) = self.underline_char {
+                options.underline_char = underline_char;
+            }
+            Ok(())
+        }
+    }
+
+    /// Sets the colors and attributes given in a file entry, creating the style if there was
+    /// none.
+    fn apply_optional_style(
+        style: &mut Option<StyleOptions>,
+        foreground: Option<String>,
+        background: Option<String>,
+        attributes: Option<String>,
+    ) -> Result<(), Error> {
+        if foreground.is_none() && background.is_none() && attributes.is_none() {
+            return Ok(());
+        }
+        apply_style(
+            style.get_or_insert_with(StyleOptions::default),
+            foreground,
+            background,
+            attributes,
+        )
+    }
+
+    /// Sets the colors and attributes given in a file entry.
+    fn apply_style(
+        style: &mut StyleOptions,
+        foreground: Option<String>,
+        background: Option<String>,
+        attributes: Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(foreground) = foreground {
+            style.foreground = Some(parse_color(&foreground)?);
+        }
+        if let Some(background) = background {
+            style.background = Some(parse_color(&background)?);
+        }
+        if let Some(attributes) = attributes {
+            style.attributes = parse_attributes(&attributes)?;
+        }
+        Ok(())
+    }
+}
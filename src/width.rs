@@ -0,0 +1,348 @@
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The escape character that starts every ANSI escape sequence.
+const ESC: char = '\x1b';
+
+/// A piece of text that is either printed as-is or interpreted by the terminal.
+enum Segment<'a> {
+    /// Visible text.
+    Text(&'a str),
+    /// A complete escape sequence, such as an SGR color code or an OSC hyperlink.
+    Escape(&'a str),
+}
+
+/// Splits text into runs of visible text and escape sequences.
+struct Segments<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Segment<'a>> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let end = if self.rest.starts_with(ESC) {
+            escape_len(self.rest)
+        } else {
+            self.rest.find(ESC).unwrap_or(self.rest.len())
+        };
+        let (segment, rest) = self.rest.split_at(end);
+        self.rest = rest;
+
+        if segment.starts_with(ESC) {
+            Some(Segment::Escape(segment))
+        } else {
+            Some(Segment::Text(segment))
+        }
+    }
+}
+
+/// Returns the length in bytes of the escape sequence at the start of the given text.
+///
+/// CSI sequences (`ESC [`, which include SGR colors) end at their final byte, and OSC sequences
+/// (`ESC ]`, used for hyperlinks and titles) end at `BEL` or `ESC \`. Any other escape is taken
+/// to be `ESC` followed by a single character. An unterminated sequence runs to the end of the
+/// text.
+fn escape_len(text: &str) -> usize {
+    let mut chars = text.char_indices().skip(1);
+    match chars.next() {
+        Some((_, '[')) => chars
+            .find(|&(_, c)| ('\x40'..='\x7e').contains(&c))
+            .map_or(text.len(), |(i, c)| i + c.len_utf8()),
+        Some((_, ']')) => {
+            let mut previous = ']';
+            for (i, c) in chars {
+                if c == '\x07' || (c == '\\' && previous == ESC) {
+                    return i + 1;
+                }
+                previous = c;
+            }
+            text.len()
+        }
+        Some((i, c)) => i + c.len_utf8(),
+        None => text.len(),
+    }
+}
+
+/// Returns an iterator over the visible text and escape sequences of the given text.
+fn segments(text: &str) -> Segments<'_> {
+    Segments { rest: text }
+}
+
+/// Removes ANSI escape sequences (SGR colors and attributes, OSC hyperlinks, etc.) from text.
+///
+/// # Arguments
+///
+/// * `text` - The text to strip.
+///
+/// # Returns
+///
+/// The visible text, borrowed if `text` contained no escape sequences.
+///
+/// # Example
+///
+/// ```rust
+/// use rustic_print::width::strip_ansi;
+///
+/// assert_eq!(strip_ansi("\x1b[31mred\x1b[0m text"), "red text");
+/// assert_eq!(strip_ansi("\x1b]8;;https://example.com\x07link\x1b]8;;\x07"), "link");
+/// ```
+pub fn strip_ansi(text: &str) -> Cow<'_, str> {
+    if !text.contains(ESC) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        segments(text)
+            .filter_map(|segment| match segment {
+                Segment::Text(text) => Some(text),
+                Segment::Escape(_) => None,
+            })
+            .collect(),
+    )
+}
+
+/// Returns the number of terminal columns the given text occupies.
+///
+/// The text is measured one grapheme cluster at a time, so combining accents take no extra
+/// space, East Asian wide characters and emoji take two columns, and emoji joined with
+/// zero-width joiners count as a single glyph. ANSI escape sequences take no space, so text that
+/// is already styled can be measured too.
+///
+/// # Arguments
+///
+/// * `text` - The text to measure.
+///
+/// # Returns
+///
+/// The display width of `text` in columns.
+///
+/// # Example
+///
+/// ```rust
+/// use crossterm::style::Stylize;
+/// use rustic_print::width::display_width;
+///
+/// assert_eq!(display_width("hello"), 5);
+/// assert_eq!(display_width("Ame\u{301}lie"), 6);
+/// assert_eq!(display_width("日本語"), 6);
+/// assert_eq!(display_width("👨‍👩‍👧"), 2);
+/// assert_eq!(display_width(&"red".red().to_string()), 3);
+/// ```
+pub fn display_width(text: &str) -> usize {
+    strip_ansi(text)
+        .graphemes(true)
+        .map(UnicodeWidthStr::width)
+        .sum()
+}
+
+/// Returns the spaces needed to pad the given text to a display width.
+///
+/// # Arguments
+///
+/// * `text` - The text to pad.
+/// * `width` - The display width to pad to.
+///
+/// # Returns
+///
+/// A `String` of spaces, empty if `text` is already at least `width` columns wide.
+pub(crate) fn padding(text: &str, width: usize) -> String {
+    " ".repeat(width.saturating_sub(display_width(text)))
+}
+
+/// Shortens text to a display width, marking the cut with `…`.
+///
+/// Escape sequences are kept, so styled text stays balanced even when shortened.
+///
+/// # Arguments
+///
+/// * `text` - The text to shorten.
+/// * `width` - The display width the result must fit in.
+///
+/// # Returns
+///
+/// The text unchanged if it fits, or as many whole grapheme clusters as fit followed by `…`.
+pub(crate) fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+
+    let budget = width.saturating_sub(1);
+    let mut truncated = String::new();
+    let mut used = 0;
+    let mut cut = false;
+
+    for segment in segments(text) {
+        match segment {
+            Segment::Escape(escape) => truncated.push_str(escape),
+            Segment::Text(_) if cut => {}
+            Segment::Text(visible) => {
+                for grapheme in visible.graphemes(true) {
+                    let grapheme_width = grapheme.width();
+                    if used + grapheme_width > budget {
+                        if width > 0 {
+                            truncated.push('…');
+                        }
+                        cut = true;
+                        break;
+                    }
+                    truncated.push_str(grapheme);
+                    used += grapheme_width;
+                }
+            }
+        }
+    }
+    truncated
+}
+
+/// Wraps text into lines of at most the given display width.
+///
+/// Styling that is still in effect at the end of a line is reset there and replayed at the
+/// start of the next, so every line can be printed on its own.
+///
+/// # Arguments
+///
+/// * `text` - The text to wrap.
+/// * `width` - The display width of each line.
+///
+/// # Returns
+///
+/// The wrapped lines.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut active = String::new();
+    textwrap::wrap(text, width.max(1))
+        .into_iter()
+        .map(|line| {
+            let mut balanced = format!("{}{}", active, line);
+            track_sgr(&line, &mut active);
+            if !active.is_empty() {
+                balanced.push_str("\x1b[0m");
+            }
+            balanced
+        })
+        .collect()
+}
+
+/// Splits text into its lines, at `\n` or `\r\n`.
+///
+/// Like [`wrap`], styling that is still in effect at the end of a line is reset there and
+/// replayed at the start of the next.
+///
+/// # Arguments
+///
+/// * `text` - The text to split.
+///
+/// # Returns
+///
+/// The lines of `text`; a single empty line if `text` is empty.
+pub(crate) fn split_lines(text: &str) -> Vec<String> {
+    let mut active = String::new();
+    text.split('\n')
+        .map(|line| {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let mut balanced = format!("{}{}", active, line);
+            track_sgr(line, &mut active);
+            if !active.is_empty() {
+                balanced.push_str("\x1b[0m");
+            }
+            balanced
+        })
+        .collect()
+}
+
+/// Splits text right after every SGR sequence that resets colors or attributes.
+///
+/// Styling each piece separately lets an enclosing style be re-applied after a pre-styled
+/// fragment resets it.
+///
+/// # Arguments
+///
+/// * `text` - The text to split.
+///
+/// # Returns
+///
+/// The pieces of `text`, which concatenate back to the original.
+pub(crate) fn split_after_resets(text: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+
+    for segment in segments(text) {
+        match segment {
+            Segment::Text(visible) => offset += visible.len(),
+            Segment::Escape(escape) => {
+                offset += escape.len();
+                if is_sgr_reset(escape) {
+                    pieces.push(&text[start..offset]);
+                    start = offset;
+                }
+            }
+        }
+    }
+
+    if start < text.len() || pieces.is_empty() {
+        pieces.push(&text[start..]);
+    }
+    pieces
+}
+
+/// Records the SGR sequences in a line that are still in effect at its end.
+///
+/// Replaying the recorded sequences at the start of the next line lets a pre-styled fragment
+/// keep its style when it is wrapped.
+///
+/// # Arguments
+///
+/// * `line` - The line to scan.
+/// * `active` - The sequences in effect before the line, updated in place.
+pub(crate) fn track_sgr(line: &str, active: &mut String) {
+    for segment in segments(line) {
+        if let Segment::Escape(escape) = segment {
+            if !is_sgr(escape) {
+                continue;
+            }
+            // Partial resets are treated as full ones, as replaying a sequence that was turned
+            // off is worse than dropping one that is still in effect.
+            if is_sgr_reset(escape) {
+                active.clear();
+            } else {
+                active.push_str(escape);
+            }
+        }
+    }
+}
+
+/// Returns whether an escape sequence is an SGR (Select Graphic Rendition) sequence.
+fn is_sgr(escape: &str) -> bool {
+    escape.starts_with("\x1b[") && escape.ends_with('m')
+}
+
+/// Returns whether an SGR sequence resets the colors or attributes an enclosing style may use.
+fn is_sgr_reset(escape: &str) -> bool {
+    if !is_sgr(escape) {
+        return false;
+    }
+
+    let mut params = escape[2..escape.len() - 1].split(';');
+    while let Some(param) = params.next() {
+        match param {
+            "" | "0" | "22" | "23" | "24" | "25" | "27" | "28" | "29" | "39" | "49" => return true,
+            // Extended colors carry their own parameters, which must not be read as codes.
+            "38" | "48" | "58" => match params.next() {
+                Some("5") => {
+                    params.next();
+                }
+                Some("2") => {
+                    params.nth(2);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    false
+}